@@ -0,0 +1,563 @@
+use crate::expectation_list::ExpectationList;
+use crate::projection::indent;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure, Owned, RootExpectations};
+use alloc::format;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+struct ProjectedAspect<'e, F, T, U>
+where
+    F: for<'a> Fn(&'a T) -> &'a U,
+    T: Debug,
+    U: Debug + 'e,
+{
+    // Printed in the header of the failures, if any
+    name: Option<&'e str>,
+    projection: F,
+    expectations: ExpectationList<'e, U>,
+    _t: PhantomData<&'e T>,
+}
+
+impl<'e, F, T, U> Expectation<T> for ProjectedAspect<'e, F, T, U>
+where
+    F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+    T: Debug,
+    U: Debug + 'e,
+{
+    fn check(&self, value: &T) -> CheckResult {
+        self.expectations
+            .check((self.projection)(value))
+            .map_message(|message| match self.name {
+                Some(name) => format!(
+                    "Expectations on aspect `{}` failed:\n{}",
+                    name,
+                    indent(message)
+                ),
+                None => format!("Expectations on an aspect failed:\n{}", indent(message)),
+            })
+    }
+}
+
+pub trait AspectExpectations<'e, T, U, B>
+where
+    T: Debug + 'e,
+    U: Debug + 'e,
+    B: ExpectationBuilder<'e, U>,
+{
+    /// Add expectations on an aspect of the value, such as a field.
+    ///
+    /// Unlike [projected_by](crate::ExpectProjection::projected_by), the projection borrows the aspect
+    /// from the value, so several aspects can be checked without cloning them.
+    /// Failures are reported indented under a header,
+    /// use [named_aspect](AspectExpectations::named_aspect) to tell aspects apart in it.
+    ///
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::expectations::{EqualityExpectations, IterableEqualityExpectations};
+    /// use rxpect::AspectExpectations;
+    ///
+    /// #[derive(Debug)]
+    /// pub struct Order {
+    ///     pub total: u32,
+    ///     pub items: Vec<String>,
+    /// }
+    /// let order = Order { total: 7, items: vec!["foo".to_string()] };
+    /// expect(order)
+    ///     .aspect(|o| &o.total, |total| total.to_equal(7))
+    ///     .aspect(|o| &o.items, |items| items.to_have_item_at(0, "foo".to_string()));
+    /// ```
+    fn aspect<F>(self, projection: F, config: impl FnOnce(B) -> B) -> Self
+    where
+        F: (for<'a> Fn(&'a T) -> &'a U) + 'e;
+
+    /// Add expectations on an aspect of the value, naming it in the header of the failures
+    ///
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::expectations::StringExpectations;
+    /// use rxpect::AspectExpectations;
+    ///
+    /// #[derive(Debug)]
+    /// pub struct User {
+    ///     pub first_name: String,
+    ///     pub last_name: String,
+    /// }
+    /// let user = User { first_name: "Ada".to_string(), last_name: "Lovelace".to_string() };
+    /// expect(user)
+    ///     .named_aspect("first_name", |u| &u.first_name, |name| name.to_contain("A"))
+    ///     .named_aspect("last_name", |u| &u.last_name, |name| name.to_contain("L"));
+    /// ```
+    fn named_aspect<F>(self, name: &'e str, projection: F, config: impl FnOnce(B) -> B) -> Self
+    where
+        F: (for<'a> Fn(&'a T) -> &'a U) + 'e;
+}
+
+impl<'e, T, U, B> AspectExpectations<'e, T, U, ExpectationList<'e, U>> for B
+where
+    T: Debug + 'e,
+    U: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn aspect<F>(
+        self,
+        projection: F,
+        config: impl FnOnce(ExpectationList<'e, U>) -> ExpectationList<'e, U>,
+    ) -> Self
+    where
+        F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+    {
+        let expectations = config(ExpectationList::new());
+        self.to_pass(ProjectedAspect {
+            name: None,
+            projection,
+            expectations,
+            _t: PhantomData,
+        })
+    }
+
+    fn named_aspect<F>(
+        self,
+        name: &'e str,
+        projection: F,
+        config: impl FnOnce(ExpectationList<'e, U>) -> ExpectationList<'e, U>,
+    ) -> Self
+    where
+        F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+    {
+        let expectations = config(ExpectationList::new());
+        self.to_pass(ProjectedAspect {
+            name: Some(name),
+            projection,
+            expectations,
+            _t: PhantomData,
+        })
+    }
+}
+
+/// Expectations on an aspect of a value, such as a field.
+///
+/// Returned by [RootExpectations::with_aspect], call [parent](Aspect::parent) to go back to
+/// adding expectations on the whole value.
+/// The expectations on the aspect are added to the parent when it's returned to
+/// or when the aspect is dropped, whichever comes first.
+pub struct Aspect<'e, T, U, F, V = Owned>
+where
+    T: Debug + 'e,
+    U: Debug + 'e,
+    F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+{
+    // Only None after the expectations have been added to the parent
+    parent: Option<(RootExpectations<'e, T, V>, F)>,
+    name: Option<&'e str>,
+    expectations: ExpectationList<'e, U>,
+}
+
+impl<'e, T, U, F, V> Aspect<'e, T, U, F, V>
+where
+    T: Debug + 'e,
+    U: Debug + 'e,
+    F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+{
+    /// Go back to adding expectations on the whole value
+    pub fn parent(mut self) -> RootExpectations<'e, T, V> {
+        self.attach()
+            .expect("aspect to not have been added to the parent")
+    }
+
+    fn attach(&mut self) -> Option<RootExpectations<'e, T, V>> {
+        let (parent, projection) = self.parent.take()?;
+        let expectations = core::mem::replace(&mut self.expectations, ExpectationList::new());
+        Some(parent.to_pass(ProjectedAspect {
+            name: self.name,
+            projection,
+            expectations,
+            _t: PhantomData,
+        }))
+    }
+}
+
+impl<'e, T, U, F, V> ExpectationBuilder<'e, U> for Aspect<'e, T, U, F, V>
+where
+    T: Debug + 'e,
+    U: Debug + 'e,
+    F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+{
+    /// Add an expectation to the list of expectations on the aspect
+    fn add_expectation(&mut self, expectation: impl Expectation<U> + 'e) -> &mut Self {
+        self.expectations.push(expectation);
+        self
+    }
+}
+
+impl<'e, T, U, F, V> Drop for Aspect<'e, T, U, F, V>
+where
+    T: Debug + 'e,
+    U: Debug + 'e,
+    F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+{
+    fn drop(&mut self) {
+        // Dropping the parent checks all expectations, including the ones on this aspect
+        drop(self.attach());
+    }
+}
+
+/// Extension trait for expectations on the relationship between two aspects of a value
+pub trait RelateExpectations<'e, T> {
+    /// Expect two projections of the value, such as fields, to be related by `relation`.
+    ///
+    /// Both projected values are printed on failure.
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::RelateExpectations;
+    ///
+    /// #[derive(Debug)]
+    /// pub struct Interval {
+    ///     pub start: u32,
+    ///     pub end: u32,
+    /// }
+    /// expect(Interval { start: 3, end: 5 }).relate(|i| i.start, |i| i.end, |a, b| a < b);
+    /// ```
+    fn relate<L, R>(
+        self,
+        left: impl Fn(&T) -> L + 'e,
+        right: impl Fn(&T) -> R + 'e,
+        relation: impl Fn(&L, &R) -> bool + 'e,
+    ) -> Self
+    where
+        L: Debug + 'e,
+        R: Debug + 'e;
+
+    /// Expect two projections of the value to be related by `relation`,
+    /// using `description` in the failure message
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::RelateExpectations;
+    ///
+    /// #[derive(Debug)]
+    /// pub struct Page {
+    ///     pub size: usize,
+    ///     pub items: Vec<u32>,
+    /// }
+    /// expect(Page { size: 10, items: vec![1, 2] }).relate_described(
+    ///     "items fit on the page",
+    ///     |p| p.items.len(),
+    ///     |p| p.size,
+    ///     |len, size| len <= size,
+    /// );
+    /// ```
+    fn relate_described<L, R>(
+        self,
+        description: &'e str,
+        left: impl Fn(&T) -> L + 'e,
+        right: impl Fn(&T) -> R + 'e,
+        relation: impl Fn(&L, &R) -> bool + 'e,
+    ) -> Self
+    where
+        L: Debug + 'e,
+        R: Debug + 'e;
+}
+
+impl<'e, T, B> RelateExpectations<'e, T> for B
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn relate<L, R>(
+        self,
+        left: impl Fn(&T) -> L + 'e,
+        right: impl Fn(&T) -> R + 'e,
+        relation: impl Fn(&L, &R) -> bool + 'e,
+    ) -> Self
+    where
+        L: Debug + 'e,
+        R: Debug + 'e,
+    {
+        self.to_pass(RelationExpectation {
+            description: None,
+            left,
+            right,
+            relation,
+        })
+    }
+
+    fn relate_described<L, R>(
+        self,
+        description: &'e str,
+        left: impl Fn(&T) -> L + 'e,
+        right: impl Fn(&T) -> R + 'e,
+        relation: impl Fn(&L, &R) -> bool + 'e,
+    ) -> Self
+    where
+        L: Debug + 'e,
+        R: Debug + 'e,
+    {
+        self.to_pass(RelationExpectation {
+            description: Some(description),
+            left,
+            right,
+            relation,
+        })
+    }
+}
+
+/// Expectation for relate and relate_described
+struct RelationExpectation<'e, FL, FR, P> {
+    description: Option<&'e str>,
+    left: FL,
+    right: FR,
+    relation: P,
+}
+
+impl<T, L, R, FL, FR, P> Expectation<T> for RelationExpectation<'_, FL, FR, P>
+where
+    T: Debug,
+    L: Debug,
+    R: Debug,
+    FL: Fn(&T) -> L,
+    FR: Fn(&T) -> R,
+    P: Fn(&L, &R) -> bool,
+{
+    fn check(&self, value: &T) -> CheckResult {
+        let (left, right) = ((self.left)(value), (self.right)(value));
+        if (self.relation)(&left, &right) {
+            return CheckResult::Pass;
+        }
+        let (relation, matcher) = match self.description {
+            Some(description) => (format!("\"{}\"", description), "relate_described"),
+            None => ("the relation".into(), "relate"),
+        };
+        CheckResult::FailWith(
+            Failure::new(format!(
+                "Expected projected values to satisfy {}\n  left: {:?}\n right: {:?}\nactual: {:?}",
+                relation, left, right, value
+            ))
+            .with_expected(relation)
+            .with_actual(format!("{:?}", value))
+            .with_matcher(matcher),
+        )
+    }
+}
+
+impl<'e, T: Debug + 'e, V> RootExpectations<'e, T, V> {
+    /// Continue with expectations on an aspect of the value, such as a field.
+    ///
+    /// This is the chained counterpart of [aspect](AspectExpectations::aspect),
+    /// use [parent](Aspect::parent) to return to the whole value.
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::expectations::{EqualityExpectations, IterableCountExpectations};
+    ///
+    /// #[derive(Debug)]
+    /// pub struct Order {
+    ///     pub total: u32,
+    ///     pub items: Vec<String>,
+    /// }
+    /// let order = Order { total: 7, items: vec!["foo".to_string()] };
+    /// expect(order)
+    ///     .with_aspect(|o| &o.total)
+    ///     .to_equal(7)
+    ///     .parent()
+    ///     .with_aspect(|o| &o.items)
+    ///     .to_have_length(1);
+    /// ```
+    pub fn with_aspect<U, F>(self, projection: F) -> Aspect<'e, T, U, F, V>
+    where
+        U: Debug + 'e,
+        F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+    {
+        Aspect {
+            parent: Some((self, projection)),
+            name: None,
+            expectations: ExpectationList::new(),
+        }
+    }
+
+    /// Continue with expectations on an aspect of the value, naming it in the header of the
+    /// failures.
+    ///
+    /// This is the chained counterpart of [named_aspect](AspectExpectations::named_aspect).
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::expectations::EqualityExpectations;
+    ///
+    /// #[derive(Debug)]
+    /// pub struct Range {
+    ///     pub start: u32,
+    ///     pub end: u32,
+    /// }
+    /// expect(Range { start: 1, end: 3 })
+    ///     .with_named_aspect("start", |r| &r.start)
+    ///     .to_equal(1)
+    ///     .parent()
+    ///     .with_named_aspect("end", |r| &r.end)
+    ///     .to_equal(3);
+    /// ```
+    pub fn with_named_aspect<U, F>(self, name: &'e str, projection: F) -> Aspect<'e, T, U, F, V>
+    where
+        U: Debug + 'e,
+        F: (for<'a> Fn(&'a T) -> &'a U) + 'e,
+    {
+        Aspect {
+            parent: Some((self, projection)),
+            name: Some(name),
+            expectations: ExpectationList::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::TestExpectation;
+    use crate::{expect, AspectExpectations, CheckResult, ExpectationBuilder, RelateExpectations};
+
+    #[derive(Debug)]
+    struct Pair {
+        first: String,
+        second: Vec<u32>,
+    }
+
+    #[test]
+    pub fn that_aspects_run_all_expectations() {
+        // Given two expectations that both pass
+        let (expectation1, expected1) = TestExpectation::new(CheckResult::Pass);
+        let (expectation2, expected2) = TestExpectation::new(CheckResult::Pass);
+
+        // And expectations on two aspects of a value
+        let pair = Pair {
+            first: "foo".to_string(),
+            second: vec![1],
+        };
+        let expectations = expect(pair)
+            .aspect(|p| &p.first, |it| it.to_pass(expectation1))
+            .aspect(|p| &p.second, |it| it.to_pass(expectation2));
+
+        // When the expectations are checked
+        expectations.check();
+
+        // Then both expectations were run
+        assert!(*expected1.lock().unwrap());
+        assert!(*expected2.lock().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectations on aspect `first` failed:\n  this\n  failed")]
+    pub fn that_aspect_failures_are_indented_under_a_header() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("this\nfailed".to_string()));
+
+        // Expect a named aspect containing it to fail with an indented message
+        let pair = Pair {
+            first: "foo".to_string(),
+            second: vec![],
+        };
+        expect(pair).named_aspect("first", |p| &p.first, |it| it.to_pass(expectation));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectations on an aspect failed:\n  failed")]
+    pub fn that_unnamed_aspect_failures_have_a_generic_header() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("failed".to_string()));
+
+        // Expect an unnamed aspect containing it to fail with a header without a name
+        let pair = Pair {
+            first: "foo".to_string(),
+            second: vec![],
+        };
+        expect(pair).aspect(|p| &p.first, |it| it.to_pass(expectation));
+    }
+
+    #[test]
+    pub fn that_chained_aspects_run_all_expectations() {
+        // Given two expectations that both pass
+        let (expectation1, expected1) = TestExpectation::new(CheckResult::Pass);
+        let (expectation2, expected2) = TestExpectation::new(CheckResult::Pass);
+
+        // And chained expectations on two aspects of a value
+        let pair = Pair {
+            first: "foo".to_string(),
+            second: vec![1],
+        };
+        let expectations = expect(pair)
+            .with_aspect(|p| &p.first)
+            .to_pass(expectation1)
+            .parent()
+            .with_aspect(|p| &p.second)
+            .to_pass(expectation2)
+            .parent();
+
+        // When the expectations are checked
+        expectations.check();
+
+        // Then both expectations were run
+        assert!(*expected1.lock().unwrap());
+        assert!(*expected2.lock().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectations on aspect `second` failed:\n  failed")]
+    pub fn that_chained_aspects_are_checked_when_dropped() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("failed".to_string()));
+
+        // Expect an aspect that never returns to its parent to still be checked
+        let pair = Pair {
+            first: "foo".to_string(),
+            second: vec![],
+        };
+        expect(pair)
+            .with_named_aspect("second", |p| &p.second)
+            .to_pass(expectation);
+    }
+
+    #[test]
+    pub fn that_relate_accepts_related_aspects() {
+        // Given a pair with as many items as characters
+        let pair = Pair {
+            first: "foo".to_string(),
+            second: vec![1, 2, 3],
+        };
+
+        // Expect the lengths to be related
+        expect(pair).relate(|p| p.first.len(), |p| p.second.len(), |a, b| a == b);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected projected values to satisfy the relation\n  left: \"foo\"\n right: []\nactual: Pair"
+    )]
+    pub fn that_relate_prints_both_projected_values() {
+        // Given a pair
+        let pair = Pair {
+            first: "foo".to_string(),
+            second: vec![],
+        };
+
+        // Expect relate to fail when the relation doesn't hold
+        expect(pair).relate(
+            |p| p.first.clone(),
+            |p| p.second.clone(),
+            |a, b| a.len() == b.len(),
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected projected values to satisfy \"same length\"\n  left: 3\n right: 0"
+    )]
+    pub fn that_relate_described_includes_the_description() {
+        // Given a pair
+        let pair = Pair {
+            first: "foo".to_string(),
+            second: vec![],
+        };
+
+        // Expect relate_described to fail with the description
+        expect(pair).relate_described(
+            "same length",
+            |p| p.first.len(),
+            |p| p.second.len(),
+            |a, b| a == b,
+        );
+    }
+}
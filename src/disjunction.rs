@@ -0,0 +1,175 @@
+use crate::expectation_list::ExpectationList;
+use crate::projection::indent;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// A branch of a disjunction, configuring the expectations that make up the branch
+pub type Branch<'e, B> = Box<dyn FnOnce(B) -> B + 'e>;
+
+struct DisjunctExpectations<'e, T>(Vec<ExpectationList<'e, T>>);
+
+impl<'e, T: Debug + 'e> Expectation<T> for DisjunctExpectations<'e, T> {
+    fn check(&self, value: &T) -> CheckResult {
+        let mut causes = Vec::new();
+        for branch in &self.0 {
+            match branch.check(value).into_failure() {
+                None => return CheckResult::Pass,
+                Some(failure) => causes.push(failure),
+            }
+        }
+        let failures = causes
+            .iter()
+            .enumerate()
+            .map(|(index, failure)| format!("branch {}:\n{}", index + 1, indent(failure.message())))
+            .collect::<Vec<_>>();
+        CheckResult::FailWith(
+            Failure::new(format!(
+                "Expected at least one of the following to hold\n{}\nactual: {:?}",
+                indent(&failures.join("\n")),
+                value
+            ))
+            .with_actual(format!("{:?}", value))
+            .with_matcher("any_of")
+            .with_causes(causes),
+        )
+    }
+}
+
+pub trait DisjunctionExpectations<'e, T, B>
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    /// Combine two sets of expectations, passing if either of them passes
+    ///
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::expectations::EqualityExpectations;
+    /// use rxpect::DisjunctionExpectations;
+    ///
+    /// expect(4).or(|it| it.to_equal(4), |it| it.to_equal(5));
+    /// ```
+    fn or(self, first: impl FnOnce(B) -> B + 'e, second: impl FnOnce(B) -> B + 'e) -> Self;
+
+    /// Combine any number of sets of expectations, passing if at least one of them passes
+    ///
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::expectations::EqualityExpectations;
+    /// use rxpect::DisjunctionExpectations;
+    ///
+    /// expect(6).any_of(vec![
+    ///     Box::new(|it| it.to_equal(4)),
+    ///     Box::new(|it| it.to_equal(5)),
+    ///     Box::new(|it| it.to_equal(6)),
+    /// ]);
+    /// ```
+    fn any_of(self, branches: Vec<Branch<'e, B>>) -> Self;
+}
+
+impl<'e, T, B> DisjunctionExpectations<'e, T, ExpectationList<'e, T>> for B
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn or(
+        self,
+        first: impl FnOnce(ExpectationList<'e, T>) -> ExpectationList<'e, T> + 'e,
+        second: impl FnOnce(ExpectationList<'e, T>) -> ExpectationList<'e, T> + 'e,
+    ) -> Self {
+        self.any_of(vec![Box::new(first), Box::new(second)])
+    }
+
+    fn any_of(self, branches: Vec<Branch<'e, ExpectationList<'e, T>>>) -> Self {
+        self.to_pass(DisjunctExpectations(
+            branches
+                .into_iter()
+                .map(|branch| branch(ExpectationList::new()))
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisjunctExpectations;
+    use crate::expectation_list::ExpectationList;
+    use crate::tests::TestExpectation;
+    use crate::{
+        expect, CheckResult, DisjunctionExpectations, ExpectProjection, Expectation,
+        ExpectationBuilder, Failure,
+    };
+
+    #[test]
+    pub fn that_or_passes_when_one_branch_passes() {
+        // Given one expectation that fails and one that passes
+        let (expectation1, _) = TestExpectation::new(CheckResult::Fail("1".to_owned()));
+        let (expectation2, expected2) = TestExpectation::new(CheckResult::Pass);
+
+        // When they are combined and checked
+        expect(true)
+            .or(|it| it.to_pass(expectation1), |it| it.to_pass(expectation2))
+            .check();
+
+        // Then the passing expectation was run
+        assert!(*expected2.lock().unwrap());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected at least one of the following to hold\n  branch 1:\n    1\n  branch 2:\n    2\nactual: true"
+    )]
+    pub fn that_or_fails_when_all_branches_fail() {
+        // Given two expectations that both fail
+        let (expectation1, _) = TestExpectation::new(CheckResult::Fail("1".to_owned()));
+        let (expectation2, _) = TestExpectation::new(CheckResult::Fail("2".to_owned()));
+
+        // Expect combining them to fail with both messages
+        expect(true).or(|it| it.to_pass(expectation1), |it| it.to_pass(expectation2));
+    }
+
+    #[test]
+    pub fn that_branch_failures_are_kept_as_causes() {
+        // Given a disjunction of two failing branches
+        let (expectation1, _) = TestExpectation::new(CheckResult::Fail("1".to_owned()));
+        let (expectation2, _) = TestExpectation::new(CheckResult::Fail("2".to_owned()));
+        let mut branch1 = ExpectationList::new();
+        branch1.push(expectation1);
+        let mut branch2 = ExpectationList::new();
+        branch2.push(expectation2);
+        let disjunction = DisjunctExpectations(vec![branch1, branch2]);
+
+        // When it's checked
+        let failure = disjunction
+            .check(&true)
+            .into_failure()
+            .expect("the disjunction to fail");
+
+        // Then the failure of each branch is kept as a cause
+        assert_eq!([Failure::new("1"), Failure::new("2")], failure.causes());
+        assert_eq!(Some("any_of"), failure.matcher());
+    }
+
+    #[test]
+    pub fn that_any_of_composes_with_projections() {
+        // Given an expectation that passes
+        let (expectation, expected) = TestExpectation::new(CheckResult::Pass);
+
+        // When it's used in a projection inside a branch
+        expect(7)
+            .any_of(vec![
+                Box::new(|it| {
+                    it.to_pass(TestExpectation::new(CheckResult::Fail("1".to_owned())).0)
+                }),
+                Box::new(|it| it.projected_by(|v| v * 2, |it| it.to_pass(expectation))),
+            ])
+            .check();
+
+        // Then it was run
+        assert!(*expected.lock().unwrap());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::expectation_list::ExpectationList;
+use crate::projection::indent;
+use crate::{Expectation, ExpectationBuilder};
+use std::fmt::Debug;
+use std::panic::Location;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+/// Container for expectations on a value that should eventually pass.
+///
+/// Returned by [eventually]
+pub struct EventualExpectations<'e, T, F>
+where
+    T: Debug,
+    F: FnMut() -> T,
+{
+    timeout: Duration,
+    interval: Duration,
+    // Only None after the expectations have been checked
+    supplier: Option<F>,
+    expectations: ExpectationList<'e, T>,
+    location: &'static Location<'static>,
+}
+
+/// Create expectations that are checked repeatedly on fresh values until they pass.
+///
+/// When the expectations are checked, `supplier` is called for a new value and the expectations
+/// are run on it, sleeping for `interval` between attempts until they pass or `timeout` has elapsed.
+/// On timeout, the failure of the last attempt is reported.
+/// ```
+/// use rxpect::eventually;
+/// use rxpect::expectations::EqualityExpectations;
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+/// eventually(Duration::from_secs(5), Duration::from_millis(10), || {
+///     start.elapsed() > Duration::from_millis(50)
+/// })
+/// .to_equal(true);
+/// ```
+#[track_caller]
+pub fn eventually<'e, T, F>(
+    timeout: Duration,
+    interval: Duration,
+    supplier: F,
+) -> EventualExpectations<'e, T, F>
+where
+    T: Debug,
+    F: FnMut() -> T,
+{
+    EventualExpectations {
+        timeout,
+        interval,
+        supplier: Some(supplier),
+        expectations: ExpectationList::new(),
+        location: Location::caller(),
+    }
+}
+
+impl<T, F> EventualExpectations<'_, T, F>
+where
+    T: Debug,
+    F: FnMut() -> T,
+{
+    /// Manually run all the expectations
+    pub fn check(self) {
+        drop(self)
+    }
+
+    /// Poll the supplier until the expectations pass, or panic with the last failure
+    fn poll(&self, mut supplier: F) {
+        let start = Instant::now();
+        loop {
+            let Some(failure) = self.expectations.check(&supplier()).into_failure() else {
+                return;
+            };
+            let waited = start.elapsed();
+            if waited >= self.timeout {
+                panic!(
+                    "Expected expectations to eventually pass, but they still failed after waiting {:?}\n{}\nat {}:{}",
+                    waited,
+                    indent(failure.message()),
+                    self.location.file(),
+                    self.location.line()
+                );
+            }
+            sleep(self.interval.min(self.timeout - waited));
+        }
+    }
+}
+
+impl<'e, T, F> ExpectationBuilder<'e, T> for EventualExpectations<'e, T, F>
+where
+    T: Debug,
+    F: FnMut() -> T,
+{
+    /// Add an expectation to the list of expectations
+    fn add_expectation(&mut self, expectation: impl Expectation<T> + 'e) -> &mut Self {
+        self.expectations.push(expectation);
+        self
+    }
+}
+
+impl<T, F> Drop for EventualExpectations<'_, T, F>
+where
+    T: Debug,
+    F: FnMut() -> T,
+{
+    fn drop(&mut self) {
+        if let Some(supplier) = self.supplier.take() {
+            self.poll(supplier);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eventually;
+    use crate::expectations::EqualityExpectations;
+    use std::time::Duration;
+
+    #[test]
+    pub fn that_eventually_retries_until_the_expectations_pass() {
+        // Given a counter that increases every time it's read
+        let mut counter = 0;
+
+        // Expect it to eventually reach 3
+        eventually(Duration::from_secs(5), Duration::from_millis(1), || {
+            counter += 1;
+            counter
+        })
+        .to_equal(3);
+
+        // And the counter to not have been read again after that
+        assert_eq!(3, counter);
+    }
+
+    #[test]
+    #[should_panic(expected = "but they still failed after waiting")]
+    pub fn that_eventually_fails_after_the_timeout() {
+        // Expect a value that never changes to not eventually pass
+        eventually(Duration::from_millis(20), Duration::from_millis(5), || 1).to_equal(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n  Expectation failed (expected == actual)")]
+    pub fn that_eventually_reports_the_last_failure() {
+        // Given a counter that increases every time it's read
+        let mut counter = 0;
+
+        // Expect the failure of the last attempt to be reported
+        eventually(Duration::from_millis(20), Duration::from_millis(5), || {
+            counter += 1;
+            counter
+        })
+        .to_equal(0);
+    }
+}
@@ -1,5 +1,10 @@
-use crate::{CheckResult, Expectation, ExpectationBuilder};
-use std::fmt::Debug;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
 
 pub struct ExpectationList<'e, T>(Vec<Box<dyn Expectation<T> + 'e>>);
 
@@ -12,36 +17,157 @@ impl<'e, T: Debug> ExpectationList<'e, T> {
         self.0.push(Box::new(expectation));
     }
 
-    pub(crate) fn check(&self, value: &T) -> CheckResult {
-        let failures = self
-            .0
-            .iter()
-            .map(|e| e.check(value))
-            .filter_map(|r| match r {
-                CheckResult::Fail(message) => Some(message),
-                _ => None,
+    /// The number of expectations that have been added to this list
+    pub fn expectation_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check every expectation individually
+    pub(crate) fn check_each<'a>(&'a self, value: &'a T) -> impl Iterator<Item = CheckResult> + 'a {
+        self.0.iter().map(move |e| e.check(value))
+    }
+
+    /// Check the expectations in the order they were added, stopping at the first failure
+    pub(crate) fn check_fail_fast(&self, value: &T) -> CheckResult {
+        self.check_each(value)
+            .find(|r| !matches!(r, CheckResult::Pass))
+            .map_or(CheckResult::Pass, |failure| {
+                failure.map_message(|message| message.trim().to_owned())
             })
-            .collect::<Vec<String>>();
-        if !failures.is_empty() {
-            // TODO: ensure messages are nicely formatted
-            let message = failures
-                .into_iter()
-                .fold(String::new(), |a, b| a + &b + "\n")
-                .trim()
-                .to_owned();
-            CheckResult::Fail(message)
-        } else {
-            CheckResult::Pass
+    }
+
+    pub(crate) fn check(&self, value: &T) -> CheckResult {
+        let mut failures = self
+            .check_each(value)
+            .filter(|r| !matches!(r, CheckResult::Pass))
+            .collect::<Vec<_>>();
+        match failures.len() {
+            0 => CheckResult::Pass,
+            // A single failure keeps its structured data
+            1 => failures
+                .remove(0)
+                .map_message(|message| message.trim().to_owned()),
+            count => {
+                let causes = failures
+                    .into_iter()
+                    .filter_map(CheckResult::into_failure)
+                    .map(|failure| failure.map_message(|message| message.trim().to_owned()))
+                    .collect::<Vec<_>>();
+                let numbered = causes
+                    .iter()
+                    .enumerate()
+                    .map(|(index, failure)| numbered(index + 1, failure.message()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                CheckResult::FailWith(
+                    Failure::new(format!(
+                        "{} of {} expectations failed\n{}",
+                        count,
+                        self.0.len(),
+                        numbered
+                    ))
+                    .with_causes(causes),
+                )
+            }
         }
     }
 }
 
+/// Prefix the first line of a message with `number) ` and align the following lines with it
+fn numbered(number: usize, message: &str) -> String {
+    let prefix = format!("{}) ", number);
+    let padding = " ".repeat(prefix.len());
+    message
+        .lines()
+        .enumerate()
+        .map(|(index, line)| match index {
+            0 => format!("{}{}", prefix, line),
+            _ => format!("{}{}", padding, line),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 impl<'e, T> ExpectationBuilder<'e, T> for ExpectationList<'e, T>
 where
     T: Debug + 'e,
 {
-    fn to_pass(mut self, expectation: impl Expectation<T> + 'e) -> Self {
+    fn add_expectation(&mut self, expectation: impl Expectation<T> + 'e) -> &mut Self {
         self.push(expectation);
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ExpectationList;
+    use crate::tests::TestExpectation;
+    use crate::{CheckResult, Failure};
+
+    #[test]
+    pub fn that_a_single_failure_is_not_numbered() {
+        // Given a list with one failing and one passing expectation
+        let (failing, _) = TestExpectation::new(CheckResult::Fail("failed\n".to_owned()));
+        let (passing, _) = TestExpectation::new(CheckResult::Pass);
+        let mut list = ExpectationList::new();
+        list.push(failing);
+        list.push(passing);
+
+        // When the list is checked
+        let result = list.check(&1);
+
+        // Then the failure message is kept as is
+        if let CheckResult::Fail(message) = result {
+            assert_eq!("failed", message);
+        } else {
+            panic!("Result was a pass when failure was expected");
+        }
+    }
+
+    #[test]
+    pub fn that_multiple_failures_are_numbered() {
+        // Given a list with two failing and one passing expectation
+        let (first, _) = TestExpectation::new(CheckResult::Fail("first\nfailure".to_owned()));
+        let (passing, _) = TestExpectation::new(CheckResult::Pass);
+        let (second, _) = TestExpectation::new(CheckResult::Fail("second failure".to_owned()));
+        let mut list = ExpectationList::new();
+        list.push(first);
+        list.push(passing);
+        list.push(second);
+
+        // When the list is checked
+        let result = list.check(&1);
+
+        // Then the failures are numbered below a summary
+        if let CheckResult::FailWith(failure) = result {
+            assert_eq!(
+                "2 of 3 expectations failed\n1) first\n   failure\n2) second failure",
+                failure.message()
+            );
+        } else {
+            panic!("Result was not an aggregated failure");
+        }
+    }
+
+    #[test]
+    pub fn that_multiple_failures_are_kept_as_causes() {
+        // Given a list with a structured failure and a plain failure
+        let structured = Failure::new("first failure")
+            .with_expected("1")
+            .with_matcher("to_equal");
+        let (first, _) = TestExpectation::new(CheckResult::FailWith(structured.clone()));
+        let (second, _) = TestExpectation::new(CheckResult::Fail("second failure\n".to_owned()));
+        let mut list = ExpectationList::new();
+        list.push(first);
+        list.push(second);
+
+        // When the list is checked
+        let failure = list.check(&1).into_failure().expect("the list to fail");
+
+        // Then both failures are kept, with trimmed messages
+        assert_eq!(
+            [structured, Failure::new("second failure")],
+            failure.causes()
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::expectation_list::ExpectationList;
+use crate::{CheckResult, Expectation, ExpectationBuilder};
+use alloc::rc::Rc;
+use core::fmt::Debug;
+
+/// A reusable set of expectations that can be declared once and applied to many values
+/// with [to_satisfy_all](SatisfyAllExpectations::to_satisfy_all).
+///
+/// Cloning a set is cheap, the clones share the same expectations.
+/// ```
+/// use rxpect::{expect, ExpectationSet, SatisfyAllExpectations};
+/// use rxpect::expectations::{NumberExpectations, OrderExpectations};
+///
+/// fn valid_percentage() -> ExpectationSet<'static, i32> {
+///     ExpectationSet::new(|it| it.to_be_between(0, 100))
+/// }
+///
+/// let percentage = valid_percentage();
+/// expect(42).to_satisfy_all(&percentage);
+/// expect(100).to_satisfy_all(&percentage).to_be_positive();
+/// ```
+pub struct ExpectationSet<'e, T>(Rc<ExpectationList<'e, T>>);
+
+impl<'e, T: Debug + 'e> ExpectationSet<'e, T> {
+    /// Create a set with the expectations added by `config`
+    pub fn new(config: impl FnOnce(ExpectationList<'e, T>) -> ExpectationList<'e, T>) -> Self {
+        ExpectationSet(Rc::new(config(ExpectationList::new())))
+    }
+
+    /// The number of expectations in this set
+    pub fn expectation_count(&self) -> usize {
+        self.0.expectation_count()
+    }
+}
+
+impl<T> Clone for ExpectationSet<'_, T> {
+    fn clone(&self) -> Self {
+        ExpectationSet(Rc::clone(&self.0))
+    }
+}
+
+/// A set checks all of its expectations and aggregates the failures like a chain of
+/// expectations does
+impl<T: Debug> Expectation<T> for ExpectationSet<'_, T> {
+    fn check(&self, value: &T) -> CheckResult {
+        self.0.check(value)
+    }
+}
+
+/// Extension trait for applying an [ExpectationSet]
+pub trait SatisfyAllExpectations<'e, T> {
+    /// Expect the value to satisfy every expectation in `set`
+    /// ```
+    /// # use rxpect::{expect, ExpectationSet, SatisfyAllExpectations};
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// let identifier = ExpectationSet::new(|it| it.to_contain("_").to_have_trimmed_length(10));
+    /// expect("snake_case").to_satisfy_all(&identifier);
+    /// ```
+    fn to_satisfy_all(self, set: &ExpectationSet<'e, T>) -> Self;
+}
+
+impl<'e, T, B> SatisfyAllExpectations<'e, T> for B
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_satisfy_all(self, set: &ExpectationSet<'e, T>) -> Self {
+        self.to_pass(set.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExpectationSet, SatisfyAllExpectations};
+    use crate::expect;
+    use crate::expectations::{EqualityExpectations, NumberExpectations};
+    use alloc::rc::Rc;
+
+    fn positive_and_not_seven() -> ExpectationSet<'static, i32> {
+        ExpectationSet::new(|it| it.to_be_positive().to_not_equal(7))
+    }
+
+    #[test]
+    pub fn that_to_satisfy_all_accepts_values_satisfying_the_set() {
+        // Given a set of expectations
+        let set = positive_and_not_seven();
+
+        // Expect it to be applied to several values
+        expect(1).to_satisfy_all(&set);
+        expect(8).to_satisfy_all(&set).to_equal(8);
+    }
+
+    #[test]
+    pub fn that_clones_share_the_expectations() {
+        // Given a set and a shared clone of it
+        let set = positive_and_not_seven();
+        let shared = Rc::new(set.clone());
+
+        // Expect both to have the same expectations
+        expect(shared.expectation_count()).to_equal(2);
+        expect(set.expectation_count()).to_equal(2);
+        expect(3).to_satisfy_all(&shared);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectation failed (expected != actual)")]
+    pub fn that_to_satisfy_all_reports_a_single_failure() {
+        // Expect the failure of the only failing expectation to be reported as is
+        expect(7).to_satisfy_all(&positive_and_not_seven());
+    }
+
+    #[test]
+    #[should_panic(expected = "2 of 2 expectations failed\n1) ")]
+    pub fn that_to_satisfy_all_aggregates_failures() {
+        // Given a set where both expectations fail for the value
+        let set = ExpectationSet::new(|it| it.to_be_negative().to_equal(-1));
+
+        // Expect the failures to be aggregated
+        expect(1).to_satisfy_all(&set);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// The number of bytes on each line of a hex dump
+const BYTES_PER_LINE: usize = 16;
+
+/// Extension trait for expectations on binary data.
+///
+/// Failures render the bytes as a hex dump with offsets, hex values and ASCII
+/// rather than with `Debug`.
+pub trait BytesExpectations<'e, T> {
+    /// Expect the bytes to equal `expected`.
+    ///
+    /// On failure, the lines of the hex dumps that differ are shown.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::BytesExpectations;
+    ///
+    /// expect(vec![0xca, 0xfe, 0xba, 0xbe]).to_equal_bytes([0xca, 0xfe, 0xba, 0xbe]);
+    /// ```
+    fn to_equal_bytes(self, expected: impl AsRef<[u8]>) -> Self;
+
+    /// Expect the bytes to start with `prefix`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::BytesExpectations;
+    ///
+    /// expect(b"\x89PNG\r\n\x1a\n...").to_start_with_bytes(b"\x89PNG");
+    /// ```
+    fn to_start_with_bytes(self, prefix: impl AsRef<[u8]>) -> Self;
+
+    /// Expect there to be exactly `length` bytes
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::BytesExpectations;
+    ///
+    /// expect("ö").to_have_byte_length(2);
+    /// ```
+    fn to_have_byte_length(self, length: usize) -> Self;
+}
+
+impl<'e, T, B> BytesExpectations<'e, T> for B
+where
+    T: AsRef<[u8]> + Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_equal_bytes(self, expected: impl AsRef<[u8]>) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_equal_bytes",
+            expected.as_ref().to_vec(),
+            |actual: &T, expected| actual.as_ref() == expected.as_slice(),
+            |actual, expected| {
+                let actual = actual.as_ref();
+                format!(
+                    "Expected bytes to be equal, but they differ\nexpected length: {}\n  actual length: {}\n{}",
+                    expected.len(),
+                    actual.len(),
+                    hex_diff(expected, actual)
+                )
+            },
+        ))
+    }
+
+    fn to_start_with_bytes(self, prefix: impl AsRef<[u8]>) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_start_with_bytes",
+            prefix.as_ref().to_vec(),
+            |actual: &T, prefix| actual.as_ref().starts_with(prefix),
+            |actual, prefix| {
+                let actual = actual.as_ref();
+                format!(
+                    "Expected bytes to start with a prefix of {} bytes, but they did not\n  actual length: {}\n{}",
+                    prefix.len(),
+                    actual.len(),
+                    hex_diff(prefix, &actual[..prefix.len().min(actual.len())])
+                )
+            },
+        ))
+    }
+
+    fn to_have_byte_length(self, length: usize) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_byte_length",
+            length,
+            |actual: &T, length| actual.as_ref().len() == *length,
+            |actual, length| {
+                let actual = actual.as_ref();
+                format!(
+                    "Expected {} bytes, but there were {}\n{}",
+                    length,
+                    actual.len(),
+                    hex_dump(actual)
+                        .iter()
+                        .map(|line| format!("  {}", line))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )
+            },
+        ))
+    }
+}
+
+/// Render the lines of a hex dump of `bytes`, each with the offset, the hex values and ASCII
+fn hex_dump(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .chunks(BYTES_PER_LINE)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let hex = chunk
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ascii = chunk
+                .iter()
+                .map(|&byte| match byte {
+                    b' ' => ' ',
+                    _ if byte.is_ascii_graphic() => byte as char,
+                    _ => '.',
+                })
+                .collect::<String>();
+            format!(
+                "{:08x}  {:<width$}  |{}|",
+                line * BYTES_PER_LINE,
+                hex,
+                ascii,
+                width = BYTES_PER_LINE * 3 - 1
+            )
+        })
+        .collect()
+}
+
+/// Render the lines of the hex dumps that differ, with expected lines prefixed by `-`
+/// and actual lines prefixed by `+`
+fn hex_diff(expected: &[u8], actual: &[u8]) -> String {
+    let expected = hex_dump(expected);
+    let actual = hex_dump(actual);
+    let mut diff = vec![String::from("- expected, + actual:")];
+    for line in 0..expected.len().max(actual.len()) {
+        let (expected, actual) = (expected.get(line), actual.get(line));
+        if expected != actual {
+            diff.extend(expected.map(|line| format!("- {}", line)));
+            diff.extend(actual.map(|line| format!("+ {}", line)));
+        }
+    }
+    diff.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hex_dump, BytesExpectations};
+    use crate::expect;
+
+    #[test]
+    pub fn that_hex_dump_renders_offsets_hex_and_ascii() {
+        // Given bytes spanning two lines
+        let bytes = b"Hello, world!\n\x00\xffrxpect";
+
+        // Expect them to be rendered 16 bytes per line
+        assert_eq!(
+            vec![
+                "00000000  48 65 6c 6c 6f 2c 20 77 6f 72 6c 64 21 0a 00 ff  |Hello, world!...|",
+                "00000010  72 78 70 65 63 74                                |rxpect|",
+            ],
+            hex_dump(bytes)
+        );
+    }
+
+    #[test]
+    pub fn that_byte_expectations_accept_matching_bytes() {
+        // Given some bytes
+        let bytes = vec![1u8, 2, 3, 4];
+
+        // Expect them to be checked against slices, arrays and vecs
+        expect(bytes)
+            .to_equal_bytes([1, 2, 3, 4])
+            .to_start_with_bytes(vec![1, 2])
+            .to_start_with_bytes([])
+            .to_have_byte_length(4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "- expected, + actual:\n- 00000010  71                                               |q|\n+ 00000010  72                                               |r|"
+    )]
+    pub fn that_to_equal_bytes_shows_the_differing_lines() {
+        // Given bytes that differ on the second line only
+        let actual = b"0123456789abcdefr";
+
+        // Expect only the second line to be shown
+        expect(actual).to_equal_bytes(b"0123456789abcdefq");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected bytes to be equal, but they differ\nexpected length: 2\n  actual length: 3"
+    )]
+    pub fn that_to_equal_bytes_reports_differing_lengths() {
+        // Given bytes that are longer than expected
+        let actual = [1u8, 2, 3];
+
+        // Expect the lengths and dumps to be shown
+        expect(actual).to_equal_bytes([1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected bytes to start with a prefix of 4 bytes, but they did not")]
+    pub fn that_to_start_with_bytes_does_not_accept_other_prefixes() {
+        // Given bytes that are shorter than the prefix
+        let actual = "GIF";
+
+        // Expect to_start_with_bytes to fail
+        expect(actual).to_start_with_bytes("GIF8");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 2 bytes, but there were 3\n  00000000  61 62 63")]
+    pub fn that_to_have_byte_length_shows_a_hex_dump() {
+        // Given a string with three bytes
+        let actual = String::from("abc");
+
+        // Expect to_have_byte_length to fail with a hex dump
+        expect(actual).to_have_byte_length(2);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::format;
+
+/// Extension trait for expectations on [char]
+pub trait CharExpectations<'e> {
+    /// Expect the character to be uppercase
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::CharExpectations;
+    ///
+    /// expect('A').to_be_uppercase();
+    /// ```
+    fn to_be_uppercase(self) -> Self;
+
+    /// Expect the character to be lowercase
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::CharExpectations;
+    ///
+    /// expect('a').to_be_lowercase();
+    /// ```
+    fn to_be_lowercase(self) -> Self;
+
+    /// Expect the character to be within the ASCII range
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::CharExpectations;
+    ///
+    /// expect('~').to_be_ascii();
+    /// ```
+    fn to_be_ascii(self) -> Self;
+
+    /// Expect the character to be a digit in the given radix
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::CharExpectations;
+    ///
+    /// expect('f').to_be_digit(16);
+    /// ```
+    fn to_be_digit(self, radix: u32) -> Self;
+
+    /// Expect the character to be whitespace
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::CharExpectations;
+    ///
+    /// expect('\t').to_be_whitespace();
+    /// ```
+    fn to_be_whitespace(self) -> Self;
+}
+
+impl<'e, B> CharExpectations<'e> for B
+where
+    B: ExpectationBuilder<'e, char>,
+{
+    fn to_be_uppercase(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_uppercase",
+            (),
+            |actual: &char, _| actual.is_uppercase(),
+            |actual, _| format!("Expected {:?} to be an uppercase character", actual),
+        ))
+    }
+
+    fn to_be_lowercase(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_lowercase",
+            (),
+            |actual: &char, _| actual.is_lowercase(),
+            |actual, _| format!("Expected {:?} to be a lowercase character", actual),
+        ))
+    }
+
+    fn to_be_ascii(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_ascii",
+            (),
+            |actual: &char, _| actual.is_ascii(),
+            |actual, _| format!("Expected {:?} to be an ASCII character", actual),
+        ))
+    }
+
+    fn to_be_digit(self, radix: u32) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_digit",
+            radix,
+            |actual: &char, radix| actual.is_digit(*radix),
+            |actual, radix| format!("Expected {:?} to be a digit in radix {}", actual, radix),
+        ))
+    }
+
+    fn to_be_whitespace(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_whitespace",
+            (),
+            |actual: &char, _| actual.is_whitespace(),
+            |actual, _| format!("Expected {:?} to be a whitespace character", actual),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CharExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_be_uppercase_accepts_uppercase_characters() {
+        // Expect uppercase characters, including non-ASCII ones, to pass
+        expect('A').to_be_uppercase();
+        expect('Ö').to_be_uppercase();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 'a' to be an uppercase character")]
+    pub fn that_to_be_uppercase_does_not_accept_lowercase_characters() {
+        // Expect a lowercase character to fail
+        expect('a').to_be_uppercase();
+    }
+
+    #[test]
+    pub fn that_to_be_lowercase_accepts_lowercase_characters() {
+        // Expect lowercase characters, including non-ASCII ones, to pass
+        expect('a').to_be_lowercase();
+        expect('ö').to_be_lowercase();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected '1' to be a lowercase character")]
+    pub fn that_to_be_lowercase_does_not_accept_digits() {
+        // Expect a digit to fail
+        expect('1').to_be_lowercase();
+    }
+
+    #[test]
+    pub fn that_to_be_ascii_accepts_ascii_characters() {
+        // Expect ASCII characters to pass
+        expect('a').to_be_ascii();
+        expect('\0').to_be_ascii();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 'é' to be an ASCII character")]
+    pub fn that_to_be_ascii_does_not_accept_other_characters() {
+        // Expect a non-ASCII character to fail
+        expect('é').to_be_ascii();
+    }
+
+    #[test]
+    pub fn that_to_be_digit_accepts_digits_in_the_radix() {
+        // Expect digits in different radixes to pass
+        expect('7').to_be_digit(8);
+        expect('F').to_be_digit(16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected '8' to be a digit in radix 8")]
+    pub fn that_to_be_digit_does_not_accept_digits_outside_the_radix() {
+        // Expect a digit outside of the radix to fail
+        expect('8').to_be_digit(8);
+    }
+
+    #[test]
+    pub fn that_to_be_whitespace_accepts_whitespace() {
+        // Expect whitespace characters to pass
+        expect(' ').to_be_whitespace();
+        expect('\n').to_be_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected '_' to be a whitespace character")]
+    pub fn that_to_be_whitespace_does_not_accept_other_characters() {
+        // Expect a non-whitespace character to fail
+        expect('_').to_be_whitespace();
+    }
+}
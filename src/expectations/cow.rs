@@ -0,0 +1,120 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::borrow::Cow;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on [Cow]
+pub trait CowExpectations<'e, 'c, U: ?Sized + ToOwned> {
+    /// Expect the value to be [Cow::Borrowed]
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::CowExpectations;
+    /// use std::borrow::Cow;
+    ///
+    /// expect(Cow::Borrowed("foo")).to_be_borrowed();
+    /// ```
+    fn to_be_borrowed(self) -> Self;
+
+    /// Expect the value to be [Cow::Owned]
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::CowExpectations;
+    /// use std::borrow::Cow;
+    ///
+    /// expect(Cow::<str>::Owned("foo".to_string())).to_be_owned();
+    /// ```
+    fn to_be_owned(self) -> Self;
+}
+
+impl<'e, 'c, U, B> CowExpectations<'e, 'c, U> for B
+where
+    U: ?Sized + ToOwned + Debug + 'c,
+    U::Owned: Debug,
+    'c: 'e,
+    B: ExpectationBuilder<'e, Cow<'c, U>>,
+{
+    fn to_be_borrowed(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_borrowed",
+            (),
+            |actual: &Cow<U>, _| matches!(actual, Cow::Borrowed(_)),
+            |actual, _| {
+                format!(
+                    "Expected Cow to be Borrowed, but it was {:?}",
+                    Variant(actual)
+                )
+            },
+        ))
+    }
+
+    fn to_be_owned(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_owned",
+            (),
+            |actual: &Cow<U>, _| matches!(actual, Cow::Owned(_)),
+            |actual, _| format!("Expected Cow to be Owned, but it was {:?}", Variant(actual)),
+        ))
+    }
+}
+
+/// Debug formatting of a Cow that includes the variant, which Cow's own Debug implementation hides
+struct Variant<'a, 'c, U: ?Sized + ToOwned>(&'a Cow<'c, U>);
+
+impl<U> Debug for Variant<'_, '_, U>
+where
+    U: ?Sized + ToOwned + Debug,
+    U::Owned: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Cow::Borrowed(value) => f.debug_tuple("Borrowed").field(value).finish(),
+            Cow::Owned(value) => f.debug_tuple("Owned").field(value).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CowExpectations;
+    use crate::expect;
+    use std::borrow::Cow;
+
+    fn normalize(value: &str) -> Cow<'_, str> {
+        if value.contains(' ') {
+            Cow::Owned(value.replace(' ', "_"))
+        } else {
+            Cow::Borrowed(value)
+        }
+    }
+
+    #[test]
+    pub fn that_to_be_borrowed_accepts_borrowed_values() {
+        // Expect a value that needs no changes to be borrowed
+        expect(normalize("foo")).to_be_borrowed();
+    }
+
+    #[test]
+    pub fn that_to_be_owned_accepts_owned_values() {
+        // Expect a value that needs changes to be owned
+        expect(normalize("foo bar")).to_be_owned();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Cow to be Borrowed, but it was Owned(\"foo_bar\")")]
+    pub fn that_to_be_borrowed_does_not_accept_owned_values() {
+        // Expect to_be_borrowed to fail for a value that was changed
+        expect(normalize("foo bar")).to_be_borrowed();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Cow to be Owned, but it was Borrowed([1, 2])")]
+    pub fn that_to_be_owned_does_not_accept_borrowed_values() {
+        // Given a borrowed slice
+        let value: Cow<[u32]> = Cow::Borrowed(&[1, 2]);
+
+        // Expect to_be_owned to fail
+        expect(value).to_be_owned();
+    }
+}
@@ -0,0 +1,131 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on [VecDeque]
+pub trait DequeExpectations<'e, C> {
+    /// Expect the item at the front of the deque to equal a value
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::DequeExpectations;
+    /// use std::collections::VecDeque;
+    ///
+    /// expect(VecDeque::from([1, 2, 3])).to_have_front(1);
+    /// ```
+    fn to_have_front(self, expected: C) -> Self;
+
+    /// Expect the item at the back of the deque to equal a value
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::DequeExpectations;
+    /// use std::collections::VecDeque;
+    ///
+    /// expect(VecDeque::from([1, 2, 3])).to_have_back(3);
+    /// ```
+    fn to_have_back(self, expected: C) -> Self;
+
+    /// Expect the deque to be empty
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::DequeExpectations;
+    /// use std::collections::VecDeque;
+    ///
+    /// expect(VecDeque::<u32>::new()).to_be_empty();
+    /// ```
+    fn to_be_empty(self) -> Self;
+}
+
+impl<'e, C, B> DequeExpectations<'e, C> for B
+where
+    C: PartialEq + Debug + 'e,
+    B: ExpectationBuilder<'e, VecDeque<C>>,
+{
+    fn to_have_front(self, expected: C) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_front",
+            expected,
+            |actual: &VecDeque<C>, expected| actual.front() == Some(expected),
+            |actual, expected| end_message("front", actual.front(), expected, actual),
+        ))
+    }
+
+    fn to_have_back(self, expected: C) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_back",
+            expected,
+            |actual: &VecDeque<C>, expected| actual.back() == Some(expected),
+            |actual, expected| end_message("back", actual.back(), expected, actual),
+        ))
+    }
+
+    fn to_be_empty(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_empty",
+            (),
+            |actual: &VecDeque<C>, _| actual.is_empty(),
+            |actual, _| format!("Expected deque to be empty, but it was {:?}", actual),
+        ))
+    }
+}
+
+fn end_message<C: Debug>(end: &str, item: Option<&C>, expected: &C, deque: &VecDeque<C>) -> String {
+    match item {
+        None => format!(
+            "Expected {} of deque to be {:?}, but the deque was empty",
+            end, expected
+        ),
+        Some(item) => format!(
+            "Expected {} of deque to be {:?}, but it was {:?}\ndeque: {:?}",
+            end, expected, item, deque
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DequeExpectations;
+    use crate::expect;
+    use std::collections::VecDeque;
+
+    #[test]
+    pub fn that_to_have_front_and_back_accept_the_ends() {
+        // Given a deque
+        let deque = VecDeque::from([1, 2, 3]);
+
+        // Expect both ends to match
+        expect(deque).to_have_front(1).to_have_back(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected front of deque to be 3, but it was 1")]
+    pub fn that_to_have_front_does_not_accept_other_values() {
+        // Given a deque
+        let deque = VecDeque::from([1, 2, 3]);
+
+        // Expect to_have_front to fail with the back value
+        expect(deque).to_have_front(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "but the deque was empty")]
+    pub fn that_to_have_back_does_not_accept_empty_deques() {
+        // Given an empty deque
+        let deque = VecDeque::new();
+
+        // Expect to_have_back to fail
+        expect(deque).to_have_back(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected deque to be empty")]
+    pub fn that_to_be_empty_does_not_accept_non_empty_deques() {
+        // Given a deque with an item
+        let deque = VecDeque::from([1]);
+
+        // Expect to_be_empty to fail
+        expect(deque).to_be_empty();
+    }
+}
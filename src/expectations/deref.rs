@@ -0,0 +1,102 @@
+use crate::expectation_list::ExpectationList;
+use crate::projection::indent;
+use crate::{CheckResult, Expectation, ExpectationBuilder};
+use alloc::format;
+use core::fmt::Debug;
+use core::ops::Deref;
+
+/// Extension trait for expectations on smart pointers and other values that implement [Deref]
+pub trait DerefExpectations<'e, T>
+where
+    T: Deref,
+    T::Target: Sized,
+{
+    /// Add expectations on the value the pointer dereferences to.
+    ///
+    /// This works like [projected_by](crate::ExpectProjection::projected_by) with `|it| &**it`,
+    /// but without having to deal with the borrow of the pointer.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{DerefExpectations, EqualityExpectations};
+    /// use std::rc::Rc;
+    ///
+    /// expect(Box::new(5)).deref(|it| it.to_equal(5));
+    /// expect(Rc::new("foo")).deref(|it| it.to_equal("foo"));
+    /// ```
+    fn deref(
+        self,
+        config: impl FnOnce(ExpectationList<'e, T::Target>) -> ExpectationList<'e, T::Target>,
+    ) -> Self;
+}
+
+impl<'e, T, B> DerefExpectations<'e, T> for B
+where
+    T: Deref + Debug + 'e,
+    T::Target: Debug + Sized + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn deref(
+        self,
+        config: impl FnOnce(ExpectationList<'e, T::Target>) -> ExpectationList<'e, T::Target>,
+    ) -> Self {
+        self.to_pass(DerefExpectation(config(ExpectationList::new())))
+    }
+}
+
+/// Expectation for deref
+struct DerefExpectation<'e, T>(ExpectationList<'e, T>);
+
+impl<T> Expectation<T> for DerefExpectation<'_, T::Target>
+where
+    T: Deref + Debug,
+    T::Target: Debug + Sized,
+{
+    fn check(&self, value: &T) -> CheckResult {
+        self.0.check(value).map_message(|message| {
+            format!(
+                "Expectations on the dereferenced value failed:\n{}",
+                indent(message)
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DerefExpectations;
+    use crate::expect;
+    use crate::expectations::EqualityExpectations;
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::string::String;
+    use alloc::sync::Arc;
+
+    #[test]
+    pub fn that_deref_runs_expectations_on_the_target() {
+        // Expect boxed, reference counted and atomically reference counted values to be checked
+        expect(Box::new(5)).deref(|it| it.to_equal(5));
+        expect(Rc::new(String::from("foo"))).deref(|it| it.to_equal(String::from("foo")));
+        expect(Arc::new(vec![1, 2])).deref(|it| it.to_equal(vec![1, 2]));
+    }
+
+    #[test]
+    pub fn that_deref_can_be_nested() {
+        // Given a pointer to a pointer
+        let value = Box::new(Rc::new("foo"));
+
+        // Expect deref to reach the innermost value
+        expect(value).deref(|it| it.deref(|it| it.to_equal("foo")));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expectations on the dereferenced value failed:\n  Expectation failed (expected == actual)"
+    )]
+    pub fn that_deref_reports_failing_expectations() {
+        // Given a boxed value
+        let value = Box::new(1);
+
+        // Expect deref to fail when the target differs
+        expect(value).deref(|it| it.to_equal(2));
+    }
+}
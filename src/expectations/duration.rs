@@ -0,0 +1,74 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::format;
+use core::time::Duration;
+
+/// Extension trait for expectations on [Duration]
+pub trait DurationExpectations<'e> {
+    /// Expect the duration to be within `percent` percent of `expected`,
+    /// i.e. `|actual - expected| <= expected * percent / 100`.
+    ///
+    /// The band scales with `expected`, so when `expected` is zero the duration must be exactly
+    /// zero. Panics if `percent` is negative.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::DurationExpectations;
+    /// use std::time::Duration;
+    ///
+    /// expect(Duration::from_millis(1040)).to_be_within_percent(Duration::from_secs(1), 5.0);
+    /// ```
+    fn to_be_within_percent(self, expected: Duration, percent: f64) -> Self;
+}
+
+impl<'e, B> DurationExpectations<'e> for B
+where
+    B: ExpectationBuilder<'e, Duration>,
+{
+    fn to_be_within_percent(self, expected: Duration, percent: f64) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_within_percent",
+            percent_band(expected, percent),
+            |actual: &Duration, (low, high)| (low..=high).contains(&actual),
+            move |actual, (low, high)| {
+                format!(
+                    "Expected {:?} to be within {}% of {:?}, but it was outside the allowed band [{:?}, {:?}]",
+                    actual, percent, expected, low, high
+                )
+            },
+        ))
+    }
+}
+
+/// The band of durations within `percent` percent of `expected`, clamped to zero
+fn percent_band(expected: Duration, percent: f64) -> (Duration, Duration) {
+    let deviation = expected.mul_f64(percent / 100.0);
+    (
+        expected.saturating_sub(deviation),
+        expected.saturating_add(deviation),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DurationExpectations;
+    use crate::expect;
+    use core::time::Duration;
+
+    #[test]
+    pub fn that_to_be_within_percent_accepts_durations_in_the_band() {
+        // Expect durations inside and on the edge of the band to pass
+        expect(Duration::from_millis(1050)).to_be_within_percent(Duration::from_secs(1), 5.0);
+        expect(Duration::from_millis(950)).to_be_within_percent(Duration::from_secs(1), 5.0);
+        expect(Duration::ZERO).to_be_within_percent(Duration::from_secs(1), 200.0);
+        expect(Duration::ZERO).to_be_within_percent(Duration::ZERO, 10.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 1.051s to be within 5% of 1s, but it was outside the allowed band [950ms, 1.05s]"
+    )]
+    pub fn that_to_be_within_percent_reports_the_allowed_band() {
+        // Expect a duration outside the band to fail
+        expect(Duration::from_millis(1051)).to_be_within_percent(Duration::from_secs(1), 5.0);
+    }
+}
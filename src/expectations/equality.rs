@@ -1,5 +1,9 @@
-use crate::{CheckResult, Expectation, ExpectationBuilder};
-use std::fmt::Debug;
+use crate::expectations::predicate::PredicateExpectation;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
 
 /// Extension trait for
 pub trait EqualityExpectations<T> {
@@ -15,6 +19,27 @@ pub trait EqualityExpectations<T> {
     /// ```
     /// asserts that `b.eq(a)` is true
     fn to_equal(self, value: T) -> Self;
+
+    /// Expect the value to not equal another value
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::EqualityExpectations;
+    ///
+    /// let a = "foo";
+    /// let b = "bar";
+    /// expect(a).to_not_equal(b);
+    /// ```
+    /// asserts that `b.eq(a)` is false
+    fn to_not_equal(self, value: T) -> Self;
+
+    /// Expect the value to not equal any of several values
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::EqualityExpectations;
+    ///
+    /// expect(4).to_not_equal_any_of([1, 2, 3]);
+    /// ```
+    fn to_not_equal_any_of(self, values: impl IntoIterator<Item = T>) -> Self;
 }
 
 impl<'e, T, B> EqualityExpectations<T> for B
@@ -25,6 +50,75 @@ where
     fn to_equal(self, value: T) -> Self {
         self.to_pass(ToEqualExpectation(value))
     }
+
+    fn to_not_equal(self, value: T) -> Self {
+        self.to_pass(ToNotEqualExpectation(value))
+    }
+
+    fn to_not_equal_any_of(self, values: impl IntoIterator<Item = T>) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_not_equal_any_of",
+            values.into_iter().collect::<Vec<_>>(),
+            |actual: &T, values| !values.contains(actual),
+            |actual, values| {
+                let matched = values.iter().find(|value| *value == actual);
+                format!(
+                    "Expected {:?} to not equal any of {:?}, but it equalled {:?}",
+                    actual,
+                    values,
+                    matched.expect("a value to match")
+                )
+            },
+        ))
+    }
+}
+
+/// Extension trait for equality expectations using a custom comparator
+pub trait EqualWithExpectations<'e, T> {
+    /// Expect the value to equal another value according to `comparator`.
+    ///
+    /// Useful for types that don't implement [PartialEq] or that need a domain-specific notion
+    /// of equality, such as ignoring a timestamp.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::EqualWithExpectations;
+    ///
+    /// #[derive(Debug)]
+    /// struct Event {
+    ///     name: &'static str,
+    ///     timestamp: u64,
+    /// }
+    ///
+    /// let event = Event { name: "login", timestamp: 1700000000 };
+    /// expect(event).to_equal_with("login", |event, name| event.name == *name);
+    /// ```
+    fn to_equal_with<U>(self, expected: U, comparator: impl Fn(&T, &U) -> bool + 'e) -> Self
+    where
+        U: Debug + 'e;
+}
+
+impl<'e, T, B> EqualWithExpectations<'e, T> for B
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_equal_with<U>(self, expected: U, comparator: impl Fn(&T, &U) -> bool + 'e) -> Self
+    where
+        U: Debug + 'e,
+    {
+        self.to_pass(PredicateExpectation::with_expected(
+            "to_equal_with",
+            (expected, comparator),
+            |(expected, _)| Some(format!("{:?}", expected)),
+            |actual: &T, (expected, comparator)| comparator(actual, expected),
+            |actual, (expected, _)| {
+                format!(
+                    "Expectation failed (expected == actual, using comparator)\nexpected: `{:?}`\n  actual: `{:?}`",
+                    expected, actual
+                )
+            },
+        ))
+    }
 }
 
 /// Expectation for to_equal
@@ -35,18 +129,163 @@ impl<T: PartialEq + Debug> Expectation<T> for ToEqualExpectation<T> {
         if self.0.eq(value) {
             CheckResult::Pass
         } else {
-            CheckResult::Fail(format!(
-                "Expectation failed (expected == actual)\nexpected: `{:?}`\n  actual: `{:?}`",
-                &self.0, value
-            ))
+            CheckResult::FailWith(
+                Failure::new(not_equal_message(&self.0, value))
+                    .with_expected(format!("{:?}", self.0))
+                    .with_actual(format!("{:?}", value))
+                    .with_matcher("to_equal"),
+            )
+        }
+    }
+}
+
+#[cfg(not(any(feature = "pretty-diff", feature = "ide-compat")))]
+fn not_equal_message<T: Debug>(expected: &T, actual: &T) -> String {
+    format!(
+        "Expectation failed (expected == actual)\nexpected: `{:?}`\n  actual: `{:?}`",
+        expected, actual
+    )
+}
+
+/// Label the values like `assert_eq!` does, with `left` being the expected value, so that
+/// test runners in IDEs can pick them up and show a diff.
+///
+/// Takes precedence over the pretty diff, which would keep test runners from parsing the values
+#[cfg(feature = "ide-compat")]
+fn not_equal_message<T: Debug>(expected: &T, actual: &T) -> String {
+    format!(
+        "Expectation failed (expected == actual)\nassertion `left == right` failed\n  left: {:?}\n right: {:?}",
+        expected, actual
+    )
+}
+
+/// Render a line-by-line diff of the pretty-printed values
+#[cfg(all(feature = "pretty-diff", not(feature = "ide-compat")))]
+fn not_equal_message<T: Debug>(expected: &T, actual: &T) -> String {
+    diff_message(expected, actual, diff_colors_enabled())
+}
+
+/// Colors are opt-in through `RXPECT_COLOR` since failure messages often end up in logs and
+/// CI output that don't render escape codes. `NO_COLOR` always turns them off.
+#[cfg(all(feature = "pretty-diff", not(feature = "ide-compat")))]
+fn diff_colors_enabled() -> bool {
+    let set = |name| std::env::var_os(name).is_some_and(|value| !value.is_empty());
+    set("RXPECT_COLOR") && !set("NO_COLOR")
+}
+
+#[cfg(all(feature = "pretty-diff", not(feature = "ide-compat")))]
+fn diff_message<T: Debug>(expected: &T, actual: &T, colored: bool) -> String {
+    use similar::{ChangeTag, TextDiff};
+
+    let expected = format!("{:#?}", expected);
+    let actual = format!("{:#?}", actual);
+    let diff = TextDiff::from_lines(&expected, &actual)
+        .iter_all_changes()
+        .map(|change| {
+            let line = change.value().trim_end_matches('\n');
+            match (change.tag(), colored) {
+                (ChangeTag::Delete, true) => format!("\x1b[31m-{}\x1b[0m", line),
+                (ChangeTag::Insert, true) => format!("\x1b[32m+{}\x1b[0m", line),
+                (ChangeTag::Delete, false) => format!("-{}", line),
+                (ChangeTag::Insert, false) => format!("+{}", line),
+                (ChangeTag::Equal, _) => format!(" {}", line),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "Expectation failed (expected == actual)\ndiff (- expected, + actual):\n{}",
+        diff
+    )
+}
+
+/// Expectation for to_not_equal
+struct ToNotEqualExpectation<T>(T);
+
+impl<T: PartialEq + Debug> Expectation<T> for ToNotEqualExpectation<T> {
+    fn check(&self, value: &T) -> CheckResult {
+        if !self.0.eq(value) {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expectation failed (expected != actual)\nexpected: `{:?}`\n  actual: `{:?}`",
+                    &self.0, value
+                ))
+                .with_expected(format!("{:?}", self.0))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_not_equal"),
+            )
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::EqualityExpectations;
-    use crate::expect;
+    use super::{EqualWithExpectations, EqualityExpectations, ToEqualExpectation};
+    use crate::{expect, CheckResult, Expectation};
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "pretty-diff", feature = "ide-compat")))]
+    #[should_panic(expected = "expected: `Point { x: 1, y: 2 }`\n  actual: `Point { x: 1, y: 3 }`")]
+    pub fn that_to_equal_prints_both_values() {
+        // Expect the failure to print both values
+        expect(Point { x: 1, y: 3 }).to_equal(Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    #[cfg(feature = "ide-compat")]
+    #[should_panic(
+        expected = "assertion `left == right` failed\n  left: Point { x: 1, y: 2 }\n right: Point { x: 1, y: 3 }"
+    )]
+    pub fn that_to_equal_labels_the_values_like_assert_eq() {
+        // Expect the failure to label the expected value as left and the actual value as right
+        expect(Point { x: 1, y: 3 }).to_equal(Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    #[cfg(all(feature = "pretty-diff", not(feature = "ide-compat")))]
+    pub fn that_to_equal_prints_a_diff() {
+        // When the diff is rendered without colors
+        let message = super::diff_message(&Point { x: 1, y: 2 }, &Point { x: 1, y: 3 }, false);
+
+        // Then the changed lines are only marked with - and +
+        assert!(message.ends_with(
+            "diff (- expected, + actual):\n Point {\n     x: 1,\n-    y: 2,\n+    y: 3,\n }"
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "pretty-diff", not(feature = "ide-compat")))]
+    pub fn that_colored_diffs_color_the_changed_lines() {
+        // When the diff is rendered with colors
+        let message = super::diff_message(&Point { x: 1, y: 2 }, &Point { x: 1, y: 3 }, true);
+
+        // Then removed lines are red and added lines are green
+        assert!(message.ends_with(
+            "diff (- expected, + actual):\n Point {\n     x: 1,\n\x1b[31m-    y: 2,\x1b[0m\n\x1b[32m+    y: 3,\x1b[0m\n }"
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "pretty-diff", feature = "ide-compat"))]
+    pub fn that_ide_compat_takes_precedence_over_pretty_diff() {
+        // When a to_equal expectation fails with both features enabled
+        let message = expect(Point { x: 1, y: 3 })
+            .to_equal(Point { x: 1, y: 2 })
+            .into_result()
+            .expect_err("the values to differ");
+
+        // Then the values are labeled like assert_eq! does and not diffed
+        assert!(message.contains("assertion `left == right` failed"));
+        assert!(!message.contains("diff (- expected, + actual)"));
+    }
 
     #[test]
     pub fn that_to_equal_accepts_equal_values() {
@@ -66,4 +305,81 @@ mod tests {
         // Expect the to_equal expectation to fail with an identical value
         expect(value).to_equal(2);
     }
+
+    #[test]
+    pub fn that_to_not_equal_accepts_unequal_values() {
+        // Given a value that implements PartialEq
+        let value = 1;
+
+        // Expect the to_not_equal expectation to pass with a different value
+        expect(value).to_not_equal(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectation failed (expected != actual)")]
+    pub fn that_to_not_equal_does_not_accept_equal_values() {
+        // Given a value that implements PartialEq
+        let value = 1;
+
+        // Expect the to_not_equal expectation to fail with an identical value
+        expect(value).to_not_equal(1);
+    }
+
+    #[test]
+    pub fn that_to_not_equal_any_of_accepts_other_values() {
+        // Given a value
+        let value = 4;
+
+        // Expect to_not_equal_any_of to pass with values that differ
+        expect(value).to_not_equal_any_of([1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but it equalled 2")]
+    pub fn that_to_not_equal_any_of_does_not_accept_listed_values() {
+        // Given a value
+        let value = 2;
+
+        // Expect to_not_equal_any_of to fail when the value is listed
+        expect(value).to_not_equal_any_of(vec![1, 2, 3]);
+    }
+
+    #[test]
+    pub fn that_to_equal_records_structured_failure_data() {
+        // Given a to_equal expectation
+        let expectation = ToEqualExpectation(1);
+
+        // When it is checked against another value
+        let result = expectation.check(&2);
+
+        // Then the failure records both values and the matcher
+        if let CheckResult::FailWith(failure) = result {
+            assert_eq!(Some("1"), failure.expected());
+            assert_eq!(Some("2"), failure.actual());
+            assert_eq!(Some("to_equal"), failure.matcher());
+        } else {
+            panic!("Result was a pass when failure was expected");
+        }
+    }
+
+    #[test]
+    pub fn that_to_equal_with_uses_the_comparator() {
+        // Given a point
+        let point = Point { x: 1, y: 3 };
+
+        // Expect to_equal_with to pass when the comparator ignores y
+        expect(point).to_equal_with(Point { x: 1, y: 2 }, |a, b| a.x == b.x);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expectation failed (expected == actual, using comparator)\nexpected: `\"FOO\"`\n  actual: `\"bar\"`"
+    )]
+    pub fn that_to_equal_with_prints_both_values() {
+        // Given a value
+        let value = "bar";
+
+        // Expect to_equal_with to fail when the comparator doesn't hold
+        expect(value).to_equal_with("FOO", |a, b| a.eq_ignore_ascii_case(b));
+    }
 }
@@ -0,0 +1,420 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use core::fmt::{Debug, Display};
+use core::ops::{Div, Mul, Sub};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The primitive floating point types.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait Float:
+    private::Sealed
+    + Copy
+    + PartialOrd
+    + Debug
+    + Display
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    #[doc(hidden)]
+    const ZERO: Self;
+    #[doc(hidden)]
+    const ONE: Self;
+    #[doc(hidden)]
+    fn is_nan(self) -> bool;
+    #[doc(hidden)]
+    fn is_finite(self) -> bool;
+    #[doc(hidden)]
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+            impl Float for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                fn is_nan(self) -> bool {
+                    <$t>::is_nan(self)
+                }
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// Extension trait for floating point expectations
+pub trait FloatExpectations<'e, T> {
+    /// Expect the value to be within `tolerance` of `expected`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::FloatExpectations;
+    ///
+    /// expect(0.1 + 0.2).to_be_close_to(0.3, 1e-9);
+    /// ```
+    /// asserts that `(actual - expected).abs() <= tolerance`
+    fn to_be_close_to(self, expected: T, tolerance: T) -> Self;
+
+    /// Expect the value to be NaN
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::FloatExpectations;
+    ///
+    /// expect(f64::NAN).to_be_nan();
+    /// ```
+    fn to_be_nan(self) -> Self;
+
+    /// Expect the value to be finite, i.e. neither infinite nor NaN
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::FloatExpectations;
+    ///
+    /// expect(1.0f32).to_be_finite();
+    /// ```
+    fn to_be_finite(self) -> Self;
+
+    /// Expect the value to be a valid probability, i.e. inside `[0.0, 1.0]` and not NaN
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::FloatExpectations;
+    ///
+    /// expect(0.25).to_be_valid_probability();
+    /// ```
+    fn to_be_valid_probability(self) -> Self;
+
+    /// Expect the value to be close to `expected`, either within the absolute tolerance `abs_tol`
+    /// or within the relative tolerance `rel_tol` of `expected`.
+    ///
+    /// This is the same criterion as numpy's `isclose` and the recommended way to compare
+    /// floating point values, since the absolute tolerance handles values near zero
+    /// and the relative tolerance handles values of large magnitude.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::FloatExpectations;
+    ///
+    /// expect(0.1 + 0.2).to_be_close_to_mixed(0.3, 1e-12, 1e-9);
+    /// expect(1e20 + 1e6).to_be_close_to_mixed(1e20, 1e-12, 1e-9);
+    /// ```
+    fn to_be_close_to_mixed(self, expected: T, abs_tol: T, rel_tol: T) -> Self;
+
+    /// Expect the ratio of the value to `other` to be within `tolerance` of `expected_ratio`.
+    ///
+    /// Fails if `other` is zero.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::FloatExpectations;
+    ///
+    /// expect(3.0).to_have_ratio_to(4.0, 0.75, 1e-9);
+    /// ```
+    fn to_have_ratio_to(self, other: T, expected_ratio: T, tolerance: T) -> Self;
+}
+
+impl<'e, T, B> FloatExpectations<'e, T> for B
+where
+    T: Float + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_be_close_to(self, expected: T, tolerance: T) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_close_to",
+            (expected, tolerance),
+            |actual: &T, (expected, tolerance)| (*actual - *expected).abs() <= *tolerance,
+            |actual, (expected, tolerance)| {
+                format!(
+                    "Expected {} to be within {} of {}, but the difference was {}",
+                    actual,
+                    tolerance,
+                    expected,
+                    (*actual - *expected).abs()
+                )
+            },
+        ))
+    }
+
+    fn to_be_nan(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_nan",
+            (),
+            |actual: &T, _| actual.is_nan(),
+            |actual, _| format!("Expected {} to be NaN", actual),
+        ))
+    }
+
+    fn to_be_finite(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_finite",
+            (),
+            |actual: &T, _| actual.is_finite(),
+            |actual, _| format!("Expected {} to be finite", actual),
+        ))
+    }
+
+    fn to_be_valid_probability(self) -> Self {
+        self.to_pass(ValidProbabilityExpectation)
+    }
+
+    fn to_be_close_to_mixed(self, expected: T, abs_tol: T, rel_tol: T) -> Self {
+        self.to_pass(CloseToMixedExpectation {
+            expected,
+            abs_tol,
+            rel_tol,
+        })
+    }
+
+    fn to_have_ratio_to(self, other: T, expected_ratio: T, tolerance: T) -> Self {
+        self.to_pass(RatioExpectation {
+            other,
+            expected_ratio,
+            tolerance,
+        })
+    }
+}
+
+/// Expectation for to_have_ratio_to
+struct RatioExpectation<T> {
+    other: T,
+    expected_ratio: T,
+    tolerance: T,
+}
+
+impl<T: Float> Expectation<T> for RatioExpectation<T> {
+    fn check(&self, value: &T) -> CheckResult {
+        let message = if self.other == T::ZERO {
+            format!(
+                "Expected the ratio of {} to {} to be {}, but the ratio to zero is undefined",
+                value, self.other, self.expected_ratio
+            )
+        } else {
+            let ratio = *value / self.other;
+            if (ratio - self.expected_ratio).abs() <= self.tolerance {
+                return CheckResult::Pass;
+            }
+            format!(
+                "Expected the ratio of {} to {} to be within {} of {}, but it was {}",
+                value, self.other, self.tolerance, self.expected_ratio, ratio
+            )
+        };
+        CheckResult::FailWith(
+            Failure::new(message)
+                .with_expected(format!("{:?}", self.expected_ratio))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_have_ratio_to"),
+        )
+    }
+}
+
+/// Expectation for to_be_valid_probability
+struct ValidProbabilityExpectation;
+
+impl<T: Float> Expectation<T> for ValidProbabilityExpectation {
+    fn check(&self, value: &T) -> CheckResult {
+        let message = if value.is_nan() {
+            format!(
+                "Expected {} to be a valid probability, but it was NaN",
+                value
+            )
+        } else if *value < T::ZERO || *value > T::ONE {
+            format!(
+                "Expected {} to be a valid probability, but it was outside of [0, 1]",
+                value
+            )
+        } else {
+            return CheckResult::Pass;
+        };
+        CheckResult::FailWith(
+            Failure::new(message)
+                .with_expected("[0, 1]")
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_be_valid_probability"),
+        )
+    }
+}
+
+/// Expectation for to_be_close_to_mixed
+struct CloseToMixedExpectation<T> {
+    expected: T,
+    abs_tol: T,
+    rel_tol: T,
+}
+
+impl<T: Float> Expectation<T> for CloseToMixedExpectation<T> {
+    fn check(&self, value: &T) -> CheckResult {
+        let difference = (*value - self.expected).abs();
+        let relative_allowance = self.rel_tol * self.expected.abs();
+        if difference <= self.abs_tol || difference <= relative_allowance {
+            return CheckResult::Pass;
+        }
+        let closest = if self.abs_tol >= relative_allowance {
+            "absolute"
+        } else {
+            "relative"
+        };
+        CheckResult::FailWith(
+            Failure::new(format!(
+                "Expected {} to be close to {}, but the difference {} exceeded both tolerances\n\
+                 absolute tolerance: {}\n\
+                 relative tolerance: {} (allowing {})\n\
+                 the {} tolerance was closest to passing",
+                value,
+                self.expected,
+                difference,
+                self.abs_tol,
+                self.rel_tol,
+                relative_allowance,
+                closest
+            ))
+            .with_expected(format!("{:?}", self.expected))
+            .with_actual(format!("{:?}", value))
+            .with_matcher("to_be_close_to_mixed"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FloatExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_be_close_to_accepts_values_within_tolerance() {
+        // Given a value with a rounding error
+        let value = 0.1 + 0.2;
+
+        // Expect to_be_close_to to pass
+        expect(value).to_be_close_to(0.3, 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 1 to be within 0.25 of 1.5, but the difference was 0.5")]
+    pub fn that_to_be_close_to_does_not_accept_values_outside_tolerance() {
+        // Given a value
+        let value = 1.0f32;
+
+        // Expect to_be_close_to to fail when the value is too far off
+        expect(value).to_be_close_to(1.5, 0.25);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn that_to_be_close_to_does_not_accept_nan() {
+        // Expect NaN to not be close to anything
+        expect(f64::NAN).to_be_close_to(0.0, f64::INFINITY);
+    }
+
+    #[test]
+    pub fn that_to_be_nan_accepts_nan() {
+        // Expect NaN to be NaN
+        expect(f32::NAN).to_be_nan();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 1 to be NaN")]
+    pub fn that_to_be_nan_does_not_accept_numbers() {
+        // Expect a number to not be NaN
+        expect(1.0).to_be_nan();
+    }
+
+    #[test]
+    pub fn that_to_be_finite_accepts_finite_values() {
+        // Expect a number to be finite
+        expect(f64::MAX).to_be_finite();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected inf to be finite")]
+    pub fn that_to_be_finite_does_not_accept_infinity() {
+        // Expect infinity to not be finite
+        expect(f64::INFINITY).to_be_finite();
+    }
+
+    #[test]
+    pub fn that_to_be_valid_probability_accepts_values_in_range() {
+        // Expect the bounds and a value in between to pass
+        expect(0.0).to_be_valid_probability();
+        expect(0.5f32).to_be_valid_probability();
+        expect(1.0).to_be_valid_probability();
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of [0, 1]")]
+    pub fn that_to_be_valid_probability_does_not_accept_values_out_of_range() {
+        // Given a value above 1
+        let value = 1.01;
+
+        // Expect to_be_valid_probability to fail
+        expect(value).to_be_valid_probability();
+    }
+
+    #[test]
+    #[should_panic(expected = "it was NaN")]
+    pub fn that_to_be_valid_probability_does_not_accept_nan() {
+        // Given NaN
+        let value = f64::NAN;
+
+        // Expect to_be_valid_probability to fail
+        expect(value).to_be_valid_probability();
+    }
+
+    #[test]
+    pub fn that_to_be_close_to_mixed_accepts_values_within_either_tolerance() {
+        // Expect a value near zero to pass through the absolute tolerance
+        expect(1e-10).to_be_close_to_mixed(0.0, 1e-9, 1e-6);
+
+        // And a value of large magnitude to pass through the relative tolerance
+        expect(1e20 + 1e6).to_be_close_to_mixed(1e20, 1e-9, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "the relative tolerance was closest to passing")]
+    pub fn that_to_be_close_to_mixed_does_not_accept_values_outside_both_tolerances() {
+        // Given a value that is too far off
+        let value = 110.0f32;
+
+        // Expect to_be_close_to_mixed to fail
+        expect(value).to_be_close_to_mixed(100.0, 0.5, 0.01);
+    }
+
+    #[test]
+    pub fn that_to_have_ratio_to_accepts_matching_ratios() {
+        // Given a value that is three quarters of another
+        let value = 3.0f32;
+
+        // Expect to_have_ratio_to to pass
+        expect(value).to_have_ratio_to(4.0, 0.75, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected the ratio of 3 to 2 to be within 0.01 of 1, but it was 1.5"
+    )]
+    pub fn that_to_have_ratio_to_does_not_accept_other_ratios() {
+        // Given a value that is one and a half of another
+        let value = 3.0;
+
+        // Expect to_have_ratio_to to fail for a ratio of one
+        expect(value).to_have_ratio_to(2.0, 1.0, 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "but the ratio to zero is undefined")]
+    pub fn that_to_have_ratio_to_does_not_accept_zero() {
+        // Given any value
+        let value = 1.0;
+
+        // Expect to_have_ratio_to to fail when comparing to zero
+        expect(value).to_have_ratio_to(0.0, 1.0, 0.01);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::format;
+use core::fmt::Debug;
+use core::ptr;
+
+/// Extension trait for expectations on the identity of referenced values.
+///
+/// These are only available when the value under test is a reference, as in `expect(&value)`.
+/// Owned values are moved into the expectations and [expect_ref](crate::expect_ref) only
+/// borrows the value for the duration of the check, so neither has a meaningful address to
+/// compare.
+pub trait IdentityExpectations<'e, T: ?Sized> {
+    /// Expect the reference to point to the same instance as `other`, as determined by
+    /// [core::ptr::eq].
+    ///
+    /// References to unsized values, such as slices and trait objects, also need to agree on
+    /// their metadata, so a subslice starting at the same address is not the same instance.
+    /// Distinct zero-sized values may share an address and be considered the same instance.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IdentityExpectations;
+    /// use std::rc::Rc;
+    ///
+    /// let cached = Rc::new(String::from("foo"));
+    /// let returned = Rc::clone(&cached);
+    /// expect(returned.as_ref()).to_be_same_instance_as(cached.as_ref());
+    /// ```
+    fn to_be_same_instance_as(self, other: &'e T) -> Self;
+}
+
+impl<'e, T, B> IdentityExpectations<'e, T> for B
+where
+    T: Debug + ?Sized + 'e,
+    B: ExpectationBuilder<'e, &'e T>,
+{
+    fn to_be_same_instance_as(self, other: &'e T) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_same_instance_as",
+            other,
+            |actual: &&T, other| ptr::eq(*actual, *other),
+            |actual, other| {
+                format!(
+                    "Expected {:p} to be the same instance as {:p}\nactual: {:?}\n other: {:?}",
+                    *actual, *other, actual, other
+                )
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IdentityExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_be_same_instance_as_accepts_the_same_instance() {
+        // Given a value and a slice
+        let value = String::from("foo");
+        let values = [1, 2, 3];
+
+        // Expect references to them to be the same instance
+        expect(&value).to_be_same_instance_as(&value);
+        expect(&values[..]).to_be_same_instance_as(&values[..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "to be the same instance as")]
+    pub fn that_to_be_same_instance_as_does_not_accept_equal_copies() {
+        // Given a value and an equal copy
+        let value = String::from("foo");
+        let copy = value.clone();
+
+        // Expect them to not be the same instance
+        expect(&value).to_be_same_instance_as(&copy);
+    }
+
+    #[test]
+    #[should_panic(expected = "actual: [1, 2]\n other: [1, 2, 3]")]
+    pub fn that_to_be_same_instance_as_compares_slice_lengths() {
+        // Given a slice and a shorter slice starting at the same address
+        let values = [1, 2, 3];
+
+        // Expect them to not be the same instance
+        expect(&values[..2]).to_be_same_instance_as(&values[..]);
+    }
+}
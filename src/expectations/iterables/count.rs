@@ -0,0 +1,110 @@
+use crate::expectation_list::ExpectationList;
+use crate::expectations::predicate::PredicateExpectation;
+use crate::{ExpectProjection, ExpectationBuilder};
+use alloc::format;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on the number of items in a collection
+pub trait IterableCountExpectations<'e, I, C> {
+    /// Add expectations on the number of items in the collection
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{IterableCountExpectations, OrderExpectations};
+    ///
+    /// expect(vec![1, 2, 3]).count(|it| it.to_be_between(1, 5));
+    /// ```
+    fn count(
+        self,
+        config: impl FnOnce(ExpectationList<'e, usize>) -> ExpectationList<'e, usize>,
+    ) -> Self;
+
+    /// Expect the collection to have exactly `length` items
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableCountExpectations;
+    ///
+    /// expect(vec!["foo", "bar"]).to_have_length(2);
+    /// ```
+    fn to_have_length(self, length: usize) -> Self;
+}
+
+impl<'e, I, C, B> IterableCountExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn count(
+        self,
+        config: impl FnOnce(ExpectationList<'e, usize>) -> ExpectationList<'e, usize>,
+    ) -> Self {
+        self.projected_by(|actual: &I| actual.into_iter().count(), config)
+    }
+
+    fn to_have_length(self, length: usize) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_length",
+            length,
+            |actual: &I, length| actual.into_iter().count() == *length,
+            |actual, length| {
+                format!(
+                    "Expected iterable to have length {}, but it had {}\nactual: {:?}",
+                    length,
+                    actual.into_iter().count(),
+                    actual
+                )
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IterableCountExpectations;
+    use crate::expect;
+    use crate::expectations::EqualityExpectations;
+    use std::collections::HashSet;
+
+    #[test]
+    pub fn that_count_projects_the_number_of_items() {
+        // Given a set with three items
+        let values = HashSet::from([1, 2, 3]);
+
+        // Expect the count to be three
+        expect(values).count(|it| it.to_equal(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectation failed (expected == actual)")]
+    pub fn that_count_fails_when_the_inner_expectations_fail() {
+        // Given a collection with two items
+        let values = vec![1, 2];
+
+        // Expect count to fail when expecting three
+        expect(values).count(|it| it.to_equal(3));
+    }
+
+    #[test]
+    pub fn that_to_have_length_accepts_the_length() {
+        // Given an empty collection and one with items
+        let empty: Vec<u8> = vec![];
+        let values = [1, 2, 3];
+
+        // Expect to_have_length to pass for their lengths
+        expect(empty).to_have_length(0);
+        expect(values).to_have_length(3);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected iterable to have length 2, but it had 3\nactual: [1, 2, 3]"
+    )]
+    pub fn that_to_have_length_does_not_accept_other_lengths() {
+        // Given a collection with three items
+        let values = vec![1, 2, 3];
+
+        // Expect to_have_length to fail for another length
+        expect(values).to_have_length(2);
+    }
+}
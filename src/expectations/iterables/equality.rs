@@ -0,0 +1,1033 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+
+/// Extension trait for expectations on the items in a collection that only require [PartialEq]
+pub trait IterableEqualityExpectations<'e, I, C> {
+    /// Expect every value in `all` to appear at least once in the collection
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum State { Idle, Running, Done }
+    ///
+    /// let visited = vec![State::Idle, State::Running, State::Idle, State::Done];
+    /// expect(visited).to_cover_all_variants(&[State::Idle, State::Running, State::Done]);
+    /// ```
+    fn to_cover_all_variants(self, all: &'e [C]) -> Self;
+
+    /// Expect no item in the collection to be equal to the item right before it
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![1, 2, 1, 3]).to_have_no_immediate_repeats();
+    /// ```
+    fn to_have_no_immediate_repeats(self) -> Self;
+
+    /// Expect the collection to be a reordering of `other` as described by `indices`,
+    /// i.e. that `actual[i] == other[indices[i]]` for every index `i`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec!['c', 'a', 'b']).to_be_reordering_of(&['a', 'b', 'c'], &[2, 0, 1]);
+    /// ```
+    fn to_be_reordering_of(self, other: &'e [C], indices: &'e [usize]) -> Self;
+
+    /// Expect the item at `index` to equal `expected`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec!["a", "b", "c"]).to_have_item_at(1, "b");
+    /// ```
+    fn to_have_item_at(self, index: usize, expected: C) -> Self;
+
+    /// Expect the items at several indices to equal the expected values
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec!["a", "b", "c"]).to_have_items_at(vec![(0, "a"), (2, "c")]);
+    /// ```
+    fn to_have_items_at(self, pairs: Vec<(usize, C)>) -> Self;
+
+    /// Expect the collection to be an interleaving of `a` and `b`, i.e. to contain all items from
+    /// both with the relative order of the items from each preserved
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![1, 10, 2, 20, 3]).to_be_interleaving_of(&[1, 2, 3], &[10, 20]);
+    /// ```
+    fn to_be_interleaving_of(self, a: &'e [C], b: &'e [C]) -> Self;
+
+    /// Expect the collection to contain `values` as a contiguous run, in order
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![1, 2, 3, 4]).to_contain_subsequence(&[2, 3]);
+    /// ```
+    fn to_contain_subsequence(self, values: &'e [C]) -> Self;
+
+    /// Expect the collection to contain all of `values` in the same order,
+    /// but not necessarily next to each other
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![1, 2, 3, 4]).to_contain_ordered_subset(&[1, 3, 4]);
+    /// ```
+    fn to_contain_ordered_subset(self, values: &'e [C]) -> Self;
+
+    /// Expect the collection to equal `expected` item by item.
+    ///
+    /// On failure, an edit script of insertions, deletions and replacements that turns
+    /// the actual collection into the expected one is reported. The script is derived from
+    /// the longest common subsequence, so it isn't necessarily the shortest one.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![1, 2, 3]).to_be_equal_with_edit_script(vec![1, 2, 3]);
+    /// ```
+    fn to_be_equal_with_edit_script(self, expected: impl IntoIterator<Item = C>) -> Self;
+
+    /// Expect `value` to appear in the collection exactly one time
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![1, 2, 2, 3]).to_contain_exactly_once(3);
+    /// ```
+    fn to_contain_exactly_once(self, value: C) -> Self;
+
+    /// Expect the collection to begin with the items in `prefix`, in order
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![1, 2, 3, 4]).to_start_with_items(&[1, 2]);
+    /// ```
+    fn to_start_with_items(self, prefix: &'e [C]) -> Self;
+
+    /// Expect the collection to end with the items in `suffix`, in order
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![1, 2, 3, 4]).to_end_with_items(&[3, 4]);
+    /// ```
+    fn to_end_with_items(self, suffix: &'e [C]) -> Self;
+
+    /// Expect the collection to contain the same items as `expected` the same number of times,
+    /// in any order.
+    ///
+    /// On failure, the expected and actual count of each item whose counts differ is reported.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec!["b", "a", "b"]).to_be_multiset_equal(&["a", "b", "b"]);
+    /// ```
+    fn to_be_multiset_equal(self, expected: &'e [C]) -> Self;
+
+    /// Expect every item in the collection to also be in `other`.
+    ///
+    /// Both collections are treated as sets, so order and duplicates are ignored.
+    /// Use [to_be_multiset_equal](IterableEqualityExpectations::to_be_multiset_equal)
+    /// when the number of times each item appears matters.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec![3, 1, 1]).to_be_subset_of(&[1, 2, 3]);
+    /// ```
+    fn to_be_subset_of(self, other: &'e [C]) -> Self;
+
+    /// Expect every item in `other` to also be in the collection.
+    ///
+    /// Both collections are treated as sets, so order and duplicates are ignored.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableEqualityExpectations;
+    ///
+    /// expect(vec!["read", "write", "admin"]).to_be_superset_of(&["write", "read", "read"]);
+    /// ```
+    fn to_be_superset_of(self, other: &'e [C]) -> Self;
+}
+
+impl<'e, I, C, B> IterableEqualityExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq + Debug + 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn to_cover_all_variants(self, all: &'e [C]) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_cover_all_variants",
+            all,
+            |actual: &I, all: &&[C]| all.iter().all(|value| contains(actual, value)),
+            |actual: &I, all: &&[C]| {
+                let uncovered = all
+                    .iter()
+                    .filter(|value| !contains(actual, value))
+                    .collect::<Vec<_>>();
+                format!(
+                    "Expected all values to be covered, but some were not\nuncovered: {:?}\n   actual: {:?}",
+                    uncovered, actual
+                )
+            },
+        ))
+    }
+
+    fn to_have_no_immediate_repeats(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_no_immediate_repeats",
+            (),
+            |actual: &I, _| first_repeat(actual).is_none(),
+            |actual: &I, _| {
+                let (index, item) = first_repeat(actual).expect("a repeat to exist");
+                format!(
+                    "Expected no immediate repeats, but {:?} at index {} is equal to the item before it\nactual: {:?}",
+                    item, index, actual
+                )
+            },
+        ))
+    }
+
+    fn to_be_reordering_of(self, other: &'e [C], indices: &'e [usize]) -> Self {
+        self.to_pass(ReorderingExpectation { other, indices })
+    }
+
+    fn to_have_item_at(self, index: usize, expected: C) -> Self {
+        self.to_have_items_at(vec![(index, expected)])
+    }
+
+    fn to_have_items_at(self, pairs: Vec<(usize, C)>) -> Self {
+        self.to_pass(ItemsAtExpectation(pairs))
+    }
+
+    fn to_be_interleaving_of(self, a: &'e [C], b: &'e [C]) -> Self {
+        self.to_pass(InterleavingExpectation { a, b })
+    }
+
+    fn to_contain_subsequence(self, values: &'e [C]) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_contain_subsequence",
+            values,
+            |actual: &I, values: &&[C]| {
+                let items = actual.into_iter().collect::<Vec<_>>();
+                values.is_empty()
+                    || items
+                        .windows(values.len())
+                        .any(|window| window.iter().copied().eq(values.iter()))
+            },
+            |actual: &I, values: &&[C]| {
+                format!(
+                    "Expected collection to contain the subsequence {:?}, but it did not\nactual: {:?}",
+                    values, actual
+                )
+            },
+        ))
+    }
+
+    fn to_contain_ordered_subset(self, values: &'e [C]) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_contain_ordered_subset",
+            values,
+            |actual: &I, values: &&[C]| first_unmatched_in_order(actual, values).is_none(),
+            |actual: &I, values: &&[C]| {
+                let (index, value) =
+                    first_unmatched_in_order(actual, values).expect("an unmatched value to exist");
+                format!(
+                    "Expected collection to contain {:?} in order, but {:?} at index {} was not found after the values before it\nactual: {:?}",
+                    values, value, index, actual
+                )
+            },
+        ))
+    }
+
+    fn to_be_equal_with_edit_script(self, expected: impl IntoIterator<Item = C>) -> Self {
+        self.to_pass(EditScriptExpectation(expected.into_iter().collect()))
+    }
+
+    fn to_contain_exactly_once(self, value: C) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_contain_exactly_once",
+            value,
+            |actual: &I, value: &C| occurrences(actual, value) == 1,
+            |actual: &I, value: &C| match occurrences(actual, value) {
+                0 => format!(
+                    "Expected collection to contain {:?} exactly once, but it was not found\nactual: {:?}",
+                    value, actual
+                ),
+                count => format!(
+                    "Expected collection to contain {:?} exactly once, but it was found {} times\nactual: {:?}",
+                    value, count, actual
+                ),
+            },
+        ))
+    }
+
+    fn to_start_with_items(self, prefix: &'e [C]) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_start_with_items",
+            prefix,
+            |actual: &I, prefix: &&[C]| {
+                let items = actual.into_iter().collect::<Vec<_>>();
+                items.len() >= prefix.len()
+                    && items[..prefix.len()].iter().copied().eq(prefix.iter())
+            },
+            |actual: &I, prefix: &&[C]| {
+                format!(
+                    "Expected collection to start with {:?}, but it did not\nactual: {:?}",
+                    prefix, actual
+                )
+            },
+        ))
+    }
+
+    fn to_end_with_items(self, suffix: &'e [C]) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_end_with_items",
+            suffix,
+            |actual: &I, suffix: &&[C]| {
+                let items = actual.into_iter().collect::<Vec<_>>();
+                items.len() >= suffix.len()
+                    && items[items.len() - suffix.len()..]
+                        .iter()
+                        .copied()
+                        .eq(suffix.iter())
+            },
+            |actual: &I, suffix: &&[C]| {
+                format!(
+                    "Expected collection to end with {:?}, but it did not\nactual: {:?}",
+                    suffix, actual
+                )
+            },
+        ))
+    }
+
+    fn to_be_multiset_equal(self, expected: &'e [C]) -> Self {
+        self.to_pass(MultisetExpectation(expected))
+    }
+
+    fn to_be_subset_of(self, other: &'e [C]) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_subset_of",
+            other,
+            |actual: &I, other: &&[C]| missing_from(actual, &other.iter().collect::<Vec<_>>()).is_empty(),
+            |actual: &I, other: &&[C]| {
+                format!(
+                    "Expected items to be a subset of {:?}, but these items are not in it: {:?}\nactual: {:?}",
+                    other,
+                    missing_from(actual, &other.iter().collect::<Vec<_>>()),
+                    actual
+                )
+            },
+        ))
+    }
+
+    fn to_be_superset_of(self, other: &'e [C]) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_superset_of",
+            other,
+            |actual: &I, other: &&[C]| missing_from(*other, &actual.into_iter().collect::<Vec<_>>()).is_empty(),
+            |actual: &I, other: &&[C]| {
+                format!(
+                    "Expected items to be a superset of {:?}, but these items are missing: {:?}\nactual: {:?}",
+                    other,
+                    missing_from(*other, &actual.into_iter().collect::<Vec<_>>()),
+                    actual
+                )
+            },
+        ))
+    }
+}
+
+/// Expectation for to_be_multiset_equal
+struct MultisetExpectation<'e, C>(&'e [C]);
+
+impl<I, C> Expectation<I> for MultisetExpectation<'_, C>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq + Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let mut distinct: Vec<&C> = vec![];
+        for item in self.0.iter().chain(value) {
+            if !distinct.contains(&item) {
+                distinct.push(item);
+            }
+        }
+        let mismatches = distinct
+            .into_iter()
+            .filter_map(|item| {
+                let expected = self.0.iter().filter(|e| *e == item).count();
+                let actual = occurrences(value, item);
+                (expected != actual)
+                    .then(|| format!("  {:?}: expected {}, actual {}", item, expected, actual))
+            })
+            .collect::<Vec<_>>();
+        if mismatches.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected collections to contain the same items the same number of times, but {} item(s) had different counts\n{}\nexpected: {:?}\n  actual: {:?}",
+                    mismatches.len(),
+                    mismatches.join("\n"),
+                    self.0,
+                    value
+                ))
+                .with_expected(format!("{:?}", self.0))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_be_multiset_equal"),
+            )
+        }
+    }
+}
+
+/// Expectation for to_be_equal_with_edit_script
+struct EditScriptExpectation<C>(Vec<C>);
+
+/// A single step in turning one sequence into another, indexed by position in the original
+enum Edit<'a, C> {
+    Delete(usize, &'a C),
+    Insert(usize, &'a C),
+    Replace(usize, &'a C, &'a C),
+}
+
+impl<C: Debug> Display for Edit<'_, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Edit::Delete(index, item) => write!(f, "delete {:?} at index {}", item, index),
+            Edit::Insert(index, item) => write!(f, "insert {:?} at index {}", item, index),
+            Edit::Replace(index, from, to) => {
+                write!(f, "replace {:?} at index {} with {:?}", from, index, to)
+            }
+        }
+    }
+}
+
+/// Compute an edit script from the longest common subsequence of `actual` and `expected`
+fn edit_script<'a, C: PartialEq>(actual: &[&'a C], expected: &'a [C]) -> Vec<Edit<'a, C>> {
+    // common[i][j] is the length of the longest common subsequence of actual[i..] and expected[j..]
+    let mut common = vec![vec![0usize; expected.len() + 1]; actual.len() + 1];
+    for i in (0..actual.len()).rev() {
+        for j in (0..expected.len()).rev() {
+            common[i][j] = if *actual[i] == expected[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let mut edits: Vec<Edit<C>> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < actual.len() || j < expected.len() {
+        if i < actual.len() && j < expected.len() && *actual[i] == expected[j] {
+            i += 1;
+            j += 1;
+        } else if j == expected.len() || (i < actual.len() && common[i + 1][j] >= common[i][j + 1])
+        {
+            edits.push(Edit::Delete(i, actual[i]));
+            i += 1;
+        } else {
+            // An insertion right after deleting the previous item is a replacement
+            match edits.last() {
+                Some(Edit::Delete(index, from)) if *index + 1 == i => {
+                    let (index, from) = (*index, *from);
+                    edits.pop();
+                    edits.push(Edit::Replace(index, from, &expected[j]));
+                }
+                _ => edits.push(Edit::Insert(i, &expected[j])),
+            }
+            j += 1;
+        }
+    }
+    edits
+}
+
+impl<I, C> Expectation<I> for EditScriptExpectation<C>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq + Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let actual = value.into_iter().collect::<Vec<_>>();
+        let edits = edit_script(&actual, &self.0);
+        if edits.is_empty() {
+            return CheckResult::Pass;
+        }
+        let script = edits
+            .iter()
+            .map(|edit| format!("  {}", edit))
+            .collect::<Vec<_>>()
+            .join("\n");
+        CheckResult::FailWith(
+            Failure::new(format!(
+                "Expected collections to be equal, but {} edit(s) are needed to turn actual into expected\n{}\nexpected: {:?}\n  actual: {:?}",
+                edits.len(),
+                script,
+                self.0,
+                value
+            ))
+            .with_expected(format!("{:?}", self.0))
+            .with_actual(format!("{:?}", value))
+            .with_matcher("to_be_equal_with_edit_script"),
+        )
+    }
+}
+
+/// Find the first value that isn't present after the items that matched the values before it
+fn first_unmatched_in_order<'a, I, C>(items: &I, values: &'a [C]) -> Option<(usize, &'a C)>
+where
+    for<'b> &'b I: IntoIterator<Item = &'b C>,
+    C: PartialEq,
+{
+    let mut remaining = items.into_iter();
+    values
+        .iter()
+        .enumerate()
+        .find(|(_, value)| !remaining.any(|item| item == *value))
+}
+
+/// Expectation for to_be_reordering_of
+struct ReorderingExpectation<'e, C> {
+    other: &'e [C],
+    indices: &'e [usize],
+}
+
+impl<I, C> Expectation<I> for ReorderingExpectation<'_, C>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq + Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let length = value.into_iter().count();
+        let failure =
+            if length != self.indices.len() {
+                Some(format!(
+                    "the collection has {} items but there are {} indices",
+                    length,
+                    self.indices.len()
+                ))
+            } else {
+                value.into_iter().zip(self.indices).enumerate().find_map(
+                    |(index, (item, &source))| match self.other.get(source) {
+                        None => Some(format!(
+                            "index {} maps to {} which is out of bounds for the original",
+                            index, source
+                        )),
+                        Some(expected) if expected != item => Some(format!(
+                            "item at index {} is {:?}, but the original has {:?} at index {}",
+                            index, item, expected, source
+                        )),
+                        Some(_) => None,
+                    },
+                )
+            };
+        match failure {
+            None => CheckResult::Pass,
+            Some(failure) => CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected a reordering of the original, but {}\n original: {:?}\n  indices: {:?}\n   actual: {:?}",
+                    failure, self.other, self.indices, value
+                ))
+                .with_expected(format!(
+                    "{:?}",
+                    self.indices
+                        .iter()
+                        .map(|&index| self.other.get(index))
+                        .collect::<Vec<_>>()
+                ))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_be_reordering_of"),
+            ),
+        }
+    }
+}
+
+/// Expectation for to_have_items_at
+struct ItemsAtExpectation<C>(Vec<(usize, C)>);
+
+impl<I, C> Expectation<I> for ItemsAtExpectation<C>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq + Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let length = value.into_iter().count();
+        let failures = self
+            .0
+            .iter()
+            .filter_map(|(index, expected)| match value.into_iter().nth(*index) {
+                None => Some(format!(
+                    "  index {} is out of bounds for length {}",
+                    index, length
+                )),
+                Some(item) if item != expected => Some(format!(
+                    "  index {}: expected {:?}, actual {:?}",
+                    index, expected, item
+                )),
+                Some(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if failures.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected items at the given indices, but some did not match\n{}\nactual: {:?}",
+                    failures.join("\n"),
+                    value
+                ))
+                .with_expected(format!("{:?}", self.0))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_have_items_at"),
+            )
+        }
+    }
+}
+
+/// Expectation for to_be_interleaving_of
+struct InterleavingExpectation<'e, C> {
+    a: &'e [C],
+    b: &'e [C],
+}
+
+impl<C: PartialEq> InterleavingExpectation<'_, C> {
+    /// Length of the longest prefix of `actual` that is an interleaving of prefixes of `a` and `b`
+    fn interleaved_prefix(&self, actual: &[&C]) -> usize {
+        // reachable[i][j] is true if the first i items of a and j items of b
+        // can be interleaved into the first i + j items of actual
+        let mut reachable = vec![vec![false; self.b.len() + 1]; self.a.len() + 1];
+        reachable[0][0] = true;
+        let mut longest = 0;
+        for i in 0..=self.a.len() {
+            for j in 0..=self.b.len() {
+                if i + j > actual.len() {
+                    break;
+                }
+                if i + j > 0 {
+                    let item = actual[i + j - 1];
+                    reachable[i][j] = (i > 0 && reachable[i - 1][j] && &self.a[i - 1] == item)
+                        || (j > 0 && reachable[i][j - 1] && &self.b[j - 1] == item);
+                }
+                if reachable[i][j] {
+                    longest = longest.max(i + j);
+                }
+            }
+        }
+        longest
+    }
+}
+
+impl<I, C> Expectation<I> for InterleavingExpectation<'_, C>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq + Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let actual = value.into_iter().collect::<Vec<_>>();
+        let expected_length = self.a.len() + self.b.len();
+        let failure = if actual.len() != expected_length {
+            format!(
+                "it has {} items while the sources have {} in total",
+                actual.len(),
+                expected_length
+            )
+        } else {
+            match self.interleaved_prefix(&actual) {
+                length if length == expected_length => return CheckResult::Pass,
+                length => format!(
+                    "{:?} at index {} can't be taken from either source in order",
+                    actual[length], length
+                ),
+            }
+        };
+        CheckResult::FailWith(
+            Failure::new(format!(
+                "Expected an interleaving of the sources, but {}\n     a: {:?}\n     b: {:?}\nactual: {:?}",
+                failure, self.a, self.b, value
+            ))
+            .with_expected(format!("an interleaving of {:?} and {:?}", self.a, self.b))
+            .with_actual(format!("{:?}", value))
+            .with_matcher("to_be_interleaving_of"),
+        )
+    }
+}
+
+/// Find the first item that is equal to the item before it
+fn first_repeat<I, C>(items: &I) -> Option<(usize, &C)>
+where
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq,
+{
+    items
+        .into_iter()
+        .zip(items.into_iter().skip(1))
+        .enumerate()
+        .find(|(_, (a, b))| a == b)
+        .map(|(index, (_, item))| (index + 1, item))
+}
+
+fn contains<I, C>(items: &I, value: &C) -> bool
+where
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq,
+{
+    items.into_iter().any(|item| item == value)
+}
+
+/// The distinct items in `items` that aren't in `other`, in the order they first appear
+fn missing_from<'a, C: PartialEq>(
+    items: impl IntoIterator<Item = &'a C>,
+    other: &[&C],
+) -> Vec<&'a C> {
+    let mut missing: Vec<&C> = vec![];
+    for item in items {
+        if !other.contains(&item) && !missing.contains(&item) {
+            missing.push(item);
+        }
+    }
+    missing
+}
+
+fn occurrences<I, C>(items: &I, value: &C) -> usize
+where
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: PartialEq,
+{
+    items.into_iter().filter(|item| *item == value).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IterableEqualityExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_be_equal_with_edit_script_accepts_equal_collections() {
+        // Given a collection
+        let values = vec!["foo", "bar"];
+
+        // Expect it to equal the same items
+        expect(values).to_be_equal_with_edit_script(["foo", "bar"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "but 3 edit(s) are needed to turn actual into expected\n  delete 1 at index 0\n  replace 3 at index 2 with 5\n  insert 6 at index 4"
+    )]
+    pub fn that_to_be_equal_with_edit_script_reports_the_edit_script() {
+        // Given a collection that differs in several places
+        let values = vec![1, 2, 3, 4];
+
+        // Expect the edits to be reported
+        expect(values).to_be_equal_with_edit_script(vec![2, 5, 4, 6]);
+    }
+
+    #[test]
+    pub fn that_to_start_and_end_with_items_accept_matching_items() {
+        // Given a collection
+        let values = vec!["a", "b", "c"];
+
+        // Expect prefixes and suffixes, including the whole collection and nothing, to match
+        expect(values)
+            .to_start_with_items(&["a", "b"])
+            .to_start_with_items(&["a", "b", "c"])
+            .to_start_with_items(&[])
+            .to_end_with_items(&["c"])
+            .to_end_with_items(&["a", "b", "c"])
+            .to_end_with_items(&[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected collection to start with [2, 3], but it did not\nactual: [1, 2, 3]"
+    )]
+    pub fn that_to_start_with_items_does_not_accept_other_prefixes() {
+        // Given a collection
+        let values = vec![1, 2, 3];
+
+        // Expect to_start_with_items to fail for items further in
+        expect(values).to_start_with_items(&[2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected collection to end with [0, 1, 2, 3], but it did not")]
+    pub fn that_to_end_with_items_does_not_accept_longer_suffixes() {
+        // Given a collection
+        let values = vec![1, 2, 3];
+
+        // Expect to_end_with_items to fail for a suffix longer than the collection
+        expect(values).to_end_with_items(&[0, 1, 2, 3]);
+    }
+
+    #[test]
+    pub fn that_to_contain_exactly_once_accepts_single_occurrences() {
+        // Given a collection with a duplicate
+        let values = vec!["foo", "bar", "foo"];
+
+        // Expect the unique value to be contained exactly once
+        expect(values).to_contain_exactly_once("bar");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected collection to contain \"foo\" exactly once, but it was found 2 times\nactual: [\"foo\", \"bar\", \"foo\"]"
+    )]
+    pub fn that_to_contain_exactly_once_does_not_accept_duplicates() {
+        // Given a collection with a duplicate
+        let values = vec!["foo", "bar", "foo"];
+
+        // Expect to_contain_exactly_once to fail for the duplicate
+        expect(values).to_contain_exactly_once("foo");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected collection to contain 4 exactly once, but it was not found"
+    )]
+    pub fn that_to_contain_exactly_once_does_not_accept_missing_values() {
+        // Given a collection
+        let values = vec![1, 2, 3];
+
+        // Expect to_contain_exactly_once to fail for a missing value
+        expect(values).to_contain_exactly_once(4);
+    }
+
+    #[test]
+    pub fn that_to_contain_subsequence_accepts_contiguous_runs() {
+        // Given a collection
+        let values = vec![1, 2, 3, 4];
+
+        // Expect runs at the start, middle and end, as well as the empty run, to be found
+        expect(values)
+            .to_contain_subsequence(&[1, 2])
+            .to_contain_subsequence(&[2, 3])
+            .to_contain_subsequence(&[3, 4])
+            .to_contain_subsequence(&[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected collection to contain the subsequence [1, 3], but it did not"
+    )]
+    pub fn that_to_contain_subsequence_does_not_accept_gaps() {
+        // Given a collection
+        let values = vec![1, 2, 3, 4];
+
+        // Expect to_contain_subsequence to fail when the values aren't adjacent
+        expect(values).to_contain_subsequence(&[1, 3]);
+    }
+
+    #[test]
+    pub fn that_to_contain_ordered_subset_accepts_gaps() {
+        // Given a collection
+        let values = vec![1, 2, 3, 4];
+
+        // Expect values in order with gaps to be found
+        expect(values).to_contain_ordered_subset(&[1, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but 2 at index 1 was not found after the values before it")]
+    pub fn that_to_contain_ordered_subset_does_not_accept_other_orders() {
+        // Given a collection
+        let values = vec![1, 2, 3, 4];
+
+        // Expect to_contain_ordered_subset to fail when the order differs
+        expect(values).to_contain_ordered_subset(&[3, 2]);
+    }
+
+    #[test]
+    pub fn that_to_cover_all_variants_accepts_collections_with_all_values() {
+        // Given a collection with repeated values
+        let values = vec![1, 2, 2, 3, 1];
+
+        // Expect it to cover all distinct values
+        expect(values).to_cover_all_variants(&[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "uncovered: [4]")]
+    pub fn that_to_cover_all_variants_does_not_accept_missing_values() {
+        // Given a collection
+        let values = vec![1, 2, 3];
+
+        // Expect to_cover_all_variants to fail when a value is missing
+        expect(values).to_cover_all_variants(&[1, 2, 3, 4]);
+    }
+
+    #[test]
+    pub fn that_to_have_no_immediate_repeats_accepts_non_adjacent_duplicates() {
+        // Given a collection with a duplicate that is not adjacent
+        let values = vec![1, 2, 1, 3];
+
+        // Expect to_have_no_immediate_repeats to pass
+        expect(values).to_have_no_immediate_repeats();
+    }
+
+    #[test]
+    #[should_panic(expected = "2 at index 2 is equal to the item before it")]
+    pub fn that_to_have_no_immediate_repeats_does_not_accept_adjacent_duplicates() {
+        // Given a collection with adjacent duplicates
+        let values = vec![1, 2, 2, 3, 3];
+
+        // Expect to_have_no_immediate_repeats to fail at the first repeat
+        expect(values).to_have_no_immediate_repeats();
+    }
+
+    #[test]
+    pub fn that_to_be_reordering_of_accepts_the_described_permutation() {
+        // Given a reordered collection
+        let values = vec!["c", "a", "b"];
+
+        // Expect to_be_reordering_of to pass with the indices that produced it
+        expect(values).to_be_reordering_of(&["a", "b", "c"], &[2, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "item at index 1 is 'b', but the original has 'a' at index 0")]
+    pub fn that_to_be_reordering_of_reports_the_first_mismatch() {
+        // Given a collection that was reordered differently
+        let values = vec!['c', 'b', 'a'];
+
+        // Expect to_be_reordering_of to fail
+        expect(values).to_be_reordering_of(&['a', 'b', 'c'], &[2, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    pub fn that_to_be_reordering_of_does_not_accept_indices_out_of_bounds() {
+        // Given a collection
+        let values = vec!['a'];
+
+        // Expect to_be_reordering_of to fail if an index is out of bounds
+        expect(values).to_be_reordering_of(&['a'], &[1]);
+    }
+
+    #[test]
+    pub fn that_to_have_items_at_accepts_matching_items() {
+        // Given a collection
+        let values = vec![1, 2, 3];
+
+        // Expect the items at the given positions to match
+        expect(values)
+            .to_have_item_at(0, 1)
+            .to_have_items_at(vec![(1, 2), (2, 3)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "  index 1: expected 5, actual 2\n  index 3 is out of bounds for length 3"
+    )]
+    pub fn that_to_have_items_at_reports_mismatches_and_out_of_bounds_indices() {
+        // Given a collection
+        let values = vec![1, 2, 3];
+
+        // Expect to_have_items_at to fail and report each failing index
+        expect(values).to_have_items_at(vec![(0, 1), (1, 5), (3, 4)]);
+    }
+
+    #[test]
+    pub fn that_to_be_interleaving_of_accepts_interleavings() {
+        // Expect interleavings, including ones requiring backtracking, to pass
+        expect(vec![1, 10, 2, 20, 3]).to_be_interleaving_of(&[1, 2, 3], &[10, 20]);
+        expect(vec![1, 1, 2, 1]).to_be_interleaving_of(&[1, 2], &[1, 1]);
+        expect(Vec::<u32>::new()).to_be_interleaving_of(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "20 at index 1 can't be taken from either source in order")]
+    pub fn that_to_be_interleaving_of_does_not_accept_reordered_items() {
+        // Expect a collection that reorders one of the sources to fail
+        expect(vec![2, 20, 1, 10]).to_be_interleaving_of(&[2, 1], &[10, 20]);
+    }
+
+    #[test]
+    #[should_panic(expected = "it has 2 items while the sources have 3 in total")]
+    pub fn that_to_be_interleaving_of_does_not_accept_missing_items() {
+        // Expect a collection missing items to fail
+        expect(vec![1, 2]).to_be_interleaving_of(&[1, 2], &[3]);
+    }
+
+    #[test]
+    pub fn that_to_be_multiset_equal_accepts_permutations() {
+        // Given a permutation of a collection with duplicates
+        let values = vec![3, 1, 2, 1];
+
+        // Expect to_be_multiset_equal to pass
+        expect(values).to_be_multiset_equal(&[1, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "but 2 item(s) had different counts\n  1: expected 2, actual 1\n  4: expected 0, actual 1\nexpected: [1, 1, 2]\n  actual: [2, 1, 4]"
+    )]
+    pub fn that_to_be_multiset_equal_reports_mismatched_counts() {
+        // Given a collection with a missing duplicate and an extra item
+        let values = vec![2, 1, 4];
+
+        // Expect only the items with differing counts to be reported
+        expect(values).to_be_multiset_equal(&[1, 1, 2]);
+    }
+
+    #[test]
+    pub fn that_to_be_subset_of_ignores_order_and_duplicates() {
+        // Given a collection with duplicates
+        let values = vec![3, 1, 3];
+
+        // Expect it to be a subset of a set with more items
+        expect(values).to_be_subset_of(&[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected items to be a subset of [1, 2], but these items are not in it: [4, 3]\nactual: [4, 1, 3, 4]"
+    )]
+    pub fn that_to_be_subset_of_lists_the_extra_items() {
+        // Given a collection with items not in the other
+        let values = vec![4, 1, 3, 4];
+
+        // Expect each extra item to be listed once
+        expect(values).to_be_subset_of(&[1, 2]);
+    }
+
+    #[test]
+    pub fn that_to_be_superset_of_ignores_order_and_duplicates() {
+        // Given a collection
+        let values = vec!["a", "b", "c"];
+
+        // Expect it to be a superset of a collection with duplicates
+        expect(values).to_be_superset_of(&["c", "a", "c"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected items to be a superset of [\"a\", \"d\", \"d\"], but these items are missing: [\"d\"]"
+    )]
+    pub fn that_to_be_superset_of_lists_the_missing_items() {
+        // Given a collection
+        let values = vec!["a", "b"];
+
+        // Expect the missing item to be listed once
+        expect(values).to_be_superset_of(&["a", "d", "d"]);
+    }
+}
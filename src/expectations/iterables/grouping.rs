@@ -0,0 +1,149 @@
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Extension trait for expectations on how the items in a collection group together
+pub trait IterableGroupingExpectations<'e, I, C> {
+    /// Expect the number of items in each bucket to match the expected histogram.
+    ///
+    /// Every item is put into the bucket returned by `bucketizer`.
+    /// Buckets without any items may be left out of `expected`.
+    /// Differing buckets are reported in the order of the buckets.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableGroupingExpectations;
+    /// use std::collections::HashMap;
+    ///
+    /// let ages = vec![3, 15, 17, 42, 67];
+    /// expect(ages).to_have_histogram(
+    ///     |age| age / 10,
+    ///     HashMap::from([(0, 1), (1, 2), (4, 1), (6, 1)]),
+    /// );
+    /// ```
+    fn to_have_histogram<K, F>(self, bucketizer: F, expected: HashMap<K, usize>) -> Self
+    where
+        K: Ord + Hash + Debug + 'e,
+        F: Fn(&C) -> K + 'e;
+}
+
+impl<'e, I, C, B> IterableGroupingExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn to_have_histogram<K, F>(self, bucketizer: F, expected: HashMap<K, usize>) -> Self
+    where
+        K: Ord + Hash + Debug + 'e,
+        F: Fn(&C) -> K + 'e,
+    {
+        self.to_pass(HistogramExpectation {
+            bucketizer,
+            expected,
+            _c: PhantomData,
+        })
+    }
+}
+
+/// Expectation for to_have_histogram
+struct HistogramExpectation<C, K, F> {
+    bucketizer: F,
+    expected: HashMap<K, usize>,
+    _c: PhantomData<fn(&C)>,
+}
+
+impl<I, C, K, F> Expectation<I> for HistogramExpectation<C, K, F>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    K: Ord + Hash + Debug,
+    F: Fn(&C) -> K,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let mut actual = HashMap::new();
+        value
+            .into_iter()
+            .for_each(|item| *actual.entry((self.bucketizer)(item)).or_insert(0) += 1);
+        let mut differences = self
+            .expected
+            .iter()
+            .map(|(bucket, count)| (bucket, *count, actual.get(bucket).copied().unwrap_or(0)))
+            .chain(
+                actual
+                    .iter()
+                    .filter(|(bucket, _)| !self.expected.contains_key(bucket))
+                    .map(|(bucket, count)| (bucket, 0, *count)),
+            )
+            .filter(|(_, expected, actual)| expected != actual)
+            .collect::<Vec<_>>();
+        if differences.is_empty() {
+            CheckResult::Pass
+        } else {
+            differences.sort_by_key(|(bucket, _, _)| *bucket);
+            let differences = differences
+                .into_iter()
+                .map(|(bucket, expected, actual)| {
+                    format!("  {:?}: expected {}, actual {}", bucket, expected, actual)
+                })
+                .collect::<Vec<_>>();
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected histogram to match, but some buckets differed\n{}\nactual: {:?}",
+                    differences.join("\n"),
+                    value
+                ))
+                .with_expected(format!("{:?}", self.expected))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_have_histogram"),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IterableGroupingExpectations;
+    use crate::expect;
+    use std::collections::HashMap;
+
+    #[test]
+    pub fn that_to_have_histogram_accepts_matching_buckets() {
+        // Given a collection of words
+        let words = vec!["a", "bb", "cc", "ddd"];
+
+        // Expect the histogram of word lengths to match
+        expect(words).to_have_histogram(|w| w.len(), HashMap::from([(1, 1), (2, 2), (3, 1)]));
+    }
+
+    #[test]
+    pub fn that_to_have_histogram_ignores_expected_empty_buckets() {
+        // Given a collection
+        let values = vec![1, 1];
+
+        // Expect an expected bucket with a zero count to pass
+        expect(values).to_have_histogram(|v| *v, HashMap::from([(1, 2), (2, 0)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "  2: expected 1, actual 2\n  3: expected 0, actual 1")]
+    pub fn that_to_have_histogram_reports_differing_buckets() {
+        // Given a collection
+        let values = vec![1, 2, 2, 3];
+
+        // Expect to_have_histogram to fail and report the differences
+        expect(values).to_have_histogram(|v| *v, HashMap::from([(1, 1), (2, 1)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "  2: expected 1, actual 0\n  10: expected 0, actual 1")]
+    pub fn that_to_have_histogram_reports_buckets_in_order() {
+        // Given a collection
+        let values = vec![10];
+
+        // Expect the differing buckets to be reported in numeric order
+        expect(values).to_have_histogram(|v| *v, HashMap::from([(2, 1)]));
+    }
+}
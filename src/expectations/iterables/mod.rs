@@ -0,0 +1,20 @@
+mod count;
+mod equality;
+#[cfg(feature = "std")]
+mod grouping;
+mod numeric;
+mod order;
+mod predicate;
+mod projection;
+mod types;
+mod uniqueness;
+pub use count::*;
+pub use equality::*;
+#[cfg(feature = "std")]
+pub use grouping::*;
+pub use numeric::*;
+pub use order::*;
+pub use predicate::*;
+pub use projection::*;
+pub use types::*;
+pub use uniqueness::*;
@@ -0,0 +1,117 @@
+use crate::expectations::Number;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on collections of numbers
+pub trait IterableNumberExpectations<'e, I, C> {
+    /// Expect the average of every window of `window` consecutive items to be within `[min, max]`
+    ///
+    /// Fails if the collection has fewer than `window` items.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableNumberExpectations;
+    ///
+    /// let requests_per_second = vec![10, 30, 20, 12, 28];
+    /// expect(requests_per_second).to_have_moving_average_within(2, 15.0, 25.0);
+    /// ```
+    fn to_have_moving_average_within(self, window: usize, min: f64, max: f64) -> Self;
+}
+
+impl<'e, I, C, B> IterableNumberExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Number + 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn to_have_moving_average_within(self, window: usize, min: f64, max: f64) -> Self {
+        self.to_pass(MovingAverageExpectation { window, min, max })
+    }
+}
+
+/// Expectation for to_have_moving_average_within
+struct MovingAverageExpectation {
+    window: usize,
+    min: f64,
+    max: f64,
+}
+
+impl<I, C> Expectation<I> for MovingAverageExpectation
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Number,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let items = value
+            .into_iter()
+            .map(|item| item.as_f64())
+            .collect::<Vec<_>>();
+        let message = if self.window == 0 || items.len() < self.window {
+            format!(
+                "Expected at least {} items to compute a moving average, but there were {}\nactual: {:?}",
+                self.window.max(1),
+                items.len(),
+                value
+            )
+        } else {
+            let violation = items
+                .windows(self.window)
+                .map(|window| window.iter().sum::<f64>() / self.window as f64)
+                .enumerate()
+                .find(|(_, average)| !(self.min..=self.max).contains(average));
+            match violation {
+                None => return CheckResult::Pass,
+                Some((start, average)) => format!(
+                    "Expected every moving average over {} items to be within [{}, {}], but the window starting at index {} averaged {}\nactual: {:?}",
+                    self.window, self.min, self.max, start, average, value
+                ),
+            }
+        };
+        CheckResult::FailWith(
+            Failure::new(message)
+                .with_expected(format!("[{}, {}]", self.min, self.max))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_have_moving_average_within"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IterableNumberExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_have_moving_average_within_accepts_smooth_series() {
+        // Given a series that fluctuates around 20
+        let values = vec![10, 30, 20, 12, 28];
+
+        // Expect every average over two items to be within bounds
+        expect(values).to_have_moving_average_within(2, 15.0, 25.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the window starting at index 2 averaged 35")]
+    pub fn that_to_have_moving_average_within_reports_the_first_violating_window() {
+        // Given a series with a spike
+        let values = vec![20.0, 20.0, 30.0, 40.0, 50.0];
+
+        // Expect to_have_moving_average_within to fail at the first window with a too high average
+        expect(values).to_have_moving_average_within(2, 15.0, 25.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected at least 3 items to compute a moving average, but there were 2"
+    )]
+    pub fn that_to_have_moving_average_within_does_not_accept_too_short_series() {
+        // Given a series shorter than the window
+        let values = vec![1u8, 2];
+
+        // Expect to_have_moving_average_within to fail
+        expect(values).to_have_moving_average_within(3, 0.0, 10.0);
+    }
+}
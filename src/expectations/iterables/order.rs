@@ -0,0 +1,265 @@
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// Extension trait for expectations on the order of items in a collection
+pub trait IterableOrderExpectations<'e, I, C> {
+    /// Expect the items to be sorted in ascending order
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableOrderExpectations;
+    ///
+    /// expect(vec![1, 2, 2, 3]).to_be_sorted();
+    /// ```
+    fn to_be_sorted(self) -> Self
+    where
+        C: PartialOrd;
+
+    /// Expect the items to be sorted in ascending order according to a comparator
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableOrderExpectations;
+    ///
+    /// expect(vec![3, 2, 1]).to_be_sorted_by(|a, b| b.cmp(a));
+    /// ```
+    fn to_be_sorted_by(self, compare: impl Fn(&C, &C) -> Ordering + 'e) -> Self;
+
+    /// Expect each item to be strictly greater than the one before it
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableOrderExpectations;
+    ///
+    /// expect(vec![1, 2, 5]).to_be_strictly_increasing();
+    /// ```
+    fn to_be_strictly_increasing(self) -> Self
+    where
+        C: PartialOrd;
+
+    /// Expect each item to be strictly less than the one before it
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableOrderExpectations;
+    ///
+    /// expect(vec![5, 2, 1]).to_be_strictly_decreasing();
+    /// ```
+    fn to_be_strictly_decreasing(self) -> Self
+    where
+        C: PartialOrd;
+}
+
+impl<'e, I, C, B> IterableOrderExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Debug + 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn to_be_sorted(self) -> Self
+    where
+        C: PartialOrd,
+    {
+        self.to_pass(SortedExpectation::new(
+            "to_be_sorted",
+            "sorted",
+            |a: &C, b: &C| matches!(a.partial_cmp(b), Some(Ordering::Less | Ordering::Equal)),
+        ))
+    }
+
+    fn to_be_sorted_by(self, compare: impl Fn(&C, &C) -> Ordering + 'e) -> Self {
+        self.to_pass(SortedExpectation::new(
+            "to_be_sorted_by",
+            "sorted",
+            move |a: &C, b: &C| compare(a, b) != Ordering::Greater,
+        ))
+    }
+
+    fn to_be_strictly_increasing(self) -> Self
+    where
+        C: PartialOrd,
+    {
+        self.to_pass(SortedExpectation::new(
+            "to_be_strictly_increasing",
+            "strictly increasing",
+            |a: &C, b: &C| a.partial_cmp(b) == Some(Ordering::Less),
+        ))
+    }
+
+    fn to_be_strictly_decreasing(self) -> Self
+    where
+        C: PartialOrd,
+    {
+        self.to_pass(SortedExpectation::new(
+            "to_be_strictly_decreasing",
+            "strictly decreasing",
+            |a: &C, b: &C| a.partial_cmp(b) == Some(Ordering::Greater),
+        ))
+    }
+}
+
+/// Expectation for the order of adjacent items, described by `order` in failure messages.
+/// Items that can't be compared are considered to be out of order.
+struct SortedExpectation<C, F> {
+    matcher: &'static str,
+    order: &'static str,
+    in_order: F,
+    _c: PhantomData<fn(&C)>,
+}
+
+impl<C, F> SortedExpectation<C, F>
+where
+    F: Fn(&C, &C) -> bool,
+{
+    fn new(matcher: &'static str, order: &'static str, in_order: F) -> Self {
+        SortedExpectation {
+            matcher,
+            order,
+            in_order,
+            _c: PhantomData,
+        }
+    }
+}
+
+impl<I, C, F> Expectation<I> for SortedExpectation<C, F>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Debug,
+    F: Fn(&C, &C) -> bool,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        match first_out_of_order(value, &self.in_order) {
+            None => CheckResult::Pass,
+            Some((index, a, b)) => CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected items to be {}, but {:?} at index {} came before {:?} at index {}\nactual: {:?}",
+                    self.order,
+                    a,
+                    index,
+                    b,
+                    index + 1,
+                    value
+                ))
+                .with_expected(self.order)
+                .with_actual(format!("{:?}", value))
+                .with_matcher(self.matcher),
+            ),
+        }
+    }
+}
+
+/// Find the first adjacent pair that isn't in order
+fn first_out_of_order<'a, I, C>(
+    items: &'a I,
+    in_order: impl Fn(&C, &C) -> bool,
+) -> Option<(usize, &'a C, &'a C)>
+where
+    &'a I: IntoIterator<Item = &'a C>,
+    C: 'a,
+{
+    items
+        .into_iter()
+        .zip(items.into_iter().skip(1))
+        .enumerate()
+        .find(|(_, (a, b))| !in_order(a, b))
+        .map(|(index, (a, b))| (index, a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IterableOrderExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_be_sorted_accepts_sorted_collections() {
+        // Given a sorted collection with duplicates
+        let values = vec![1, 2, 2, 3];
+
+        // Expect to_be_sorted to pass
+        expect(values).to_be_sorted();
+    }
+
+    #[test]
+    pub fn that_to_be_sorted_accepts_empty_collections() {
+        // Given an empty collection
+        let values: Vec<i32> = vec![];
+
+        // Expect to_be_sorted to pass
+        expect(values).to_be_sorted();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected items to be sorted, but 3 at index 1 came before 2 at index 2"
+    )]
+    pub fn that_to_be_sorted_does_not_accept_unsorted_collections() {
+        // Given a collection with an out of order pair
+        let values = vec![1, 3, 2, 4];
+
+        // Expect to_be_sorted to fail at the first out of order pair
+        expect(values).to_be_sorted();
+    }
+
+    #[test]
+    #[should_panic(expected = "1.0 at index 0 came before NaN at index 1")]
+    pub fn that_to_be_sorted_does_not_accept_incomparable_items() {
+        // Given a collection with NaN
+        let values = vec![1.0, f64::NAN, 2.0];
+
+        // Expect to_be_sorted to fail
+        expect(values).to_be_sorted();
+    }
+
+    #[test]
+    pub fn that_to_be_sorted_by_uses_the_comparator() {
+        // Given a collection sorted by length
+        let values = vec!["c", "bb", "aaa"];
+
+        // Expect to_be_sorted_by to pass when comparing lengths
+        expect(values).to_be_sorted_by(|a, b| a.len().cmp(&b.len()));
+    }
+
+    #[test]
+    #[should_panic(expected = "\"c\" at index 0 came before \"bb\" at index 1")]
+    pub fn that_to_be_sorted_by_does_not_accept_unsorted_collections() {
+        // Given a collection sorted by length
+        let values = vec!["c", "bb", "aaa"];
+
+        // Expect to_be_sorted_by to fail when comparing lexicographically
+        expect(values).to_be_sorted_by(|a, b| a.cmp(b));
+    }
+
+    #[test]
+    pub fn that_strict_order_expectations_accept_strictly_ordered_collections() {
+        // Expect strictly ordered and trivially ordered collections to pass
+        expect(vec![1, 2, 5]).to_be_strictly_increasing();
+        expect(vec![5.0, 2.0, -1.0]).to_be_strictly_decreasing();
+        expect(Vec::<i32>::new()).to_be_strictly_increasing();
+        expect(vec![1]).to_be_strictly_decreasing();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected items to be strictly increasing, but 2 at index 1 came before 2 at index 2"
+    )]
+    pub fn that_to_be_strictly_increasing_does_not_accept_duplicates() {
+        // Given timestamps with a duplicate
+        let values = vec![1, 2, 2, 3];
+
+        // Expect to_be_strictly_increasing to fail at the duplicate
+        expect(values).to_be_strictly_increasing();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected items to be strictly decreasing, but 1 at index 0 came before 2 at index 1"
+    )]
+    pub fn that_to_be_strictly_decreasing_does_not_accept_increasing_pairs() {
+        // Given an increasing collection
+        let values = vec![1, 2, 3];
+
+        // Expect to_be_strictly_decreasing to fail at the first pair
+        expect(values).to_be_strictly_decreasing();
+    }
+}
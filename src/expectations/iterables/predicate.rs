@@ -0,0 +1,96 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on collections using custom predicates
+pub trait IterablePredicateExpectations<'e, I, C> {
+    /// Expect the items of the collection, taken together, to satisfy a predicate.
+    ///
+    /// The items are collected into a slice that is passed to the predicate,
+    /// and `description` is included in the failure message.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterablePredicateExpectations;
+    ///
+    /// let percentages = vec![25, 25, 50];
+    /// expect(percentages).to_satisfy_collectively(
+    ///     |items| items.iter().sum::<u32>() == 100,
+    ///     "percentages sum to 100",
+    /// );
+    /// ```
+    fn to_satisfy_collectively(
+        self,
+        predicate: impl Fn(&[C]) -> bool + 'e,
+        description: &'e str,
+    ) -> Self
+    where
+        C: Clone;
+}
+
+impl<'e, I, C, B> IterablePredicateExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Debug + 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn to_satisfy_collectively(
+        self,
+        predicate: impl Fn(&[C]) -> bool + 'e,
+        description: &'e str,
+    ) -> Self
+    where
+        C: Clone,
+    {
+        self.to_pass(PredicateExpectation::with_expected(
+            "to_satisfy_collectively",
+            (predicate, description),
+            |(_, description)| Some(description.to_string()),
+            |actual: &I, (predicate, _)| {
+                predicate(&actual.into_iter().cloned().collect::<Vec<_>>())
+            },
+            |actual, (_, description)| {
+                format!(
+                    "Expected collection to satisfy: {}\nactual: {:?}",
+                    description, actual
+                )
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IterablePredicateExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_satisfy_collectively_accepts_collections_satisfying_the_predicate() {
+        // Given a collection of contiguous IDs
+        let ids = vec![3, 4, 5, 6];
+
+        // Expect to_satisfy_collectively to pass
+        expect(ids).to_satisfy_collectively(
+            |ids| ids.windows(2).all(|w| w[1] == w[0] + 1),
+            "IDs are contiguous",
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected collection to satisfy: percentages sum to 100\nactual: [25, 50]"
+    )]
+    pub fn that_to_satisfy_collectively_reports_the_description() {
+        // Given percentages that don't sum to 100
+        let percentages = vec![25, 50];
+
+        // Expect to_satisfy_collectively to fail
+        expect(percentages).to_satisfy_collectively(
+            |items| items.iter().sum::<u32>() == 100,
+            "percentages sum to 100",
+        );
+    }
+}
@@ -0,0 +1,467 @@
+use crate::expectation_list::ExpectationList;
+use crate::projection::indent;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A boxed configuration of expectations on an item, allowing lists of different closures
+pub type ItemConfig<'e, C> = Box<dyn FnOnce(ExpectationList<'e, C>) -> ExpectationList<'e, C> + 'e>;
+
+/// Extension trait for projecting the items in a collection into further expectations
+pub trait IterableProjectionExpectations<'e, I, C> {
+    /// Pair every item with the item at the same position in `other` and add expectations on each pair.
+    ///
+    /// Fails if the collections have different lengths, and reports every pair that fails otherwise.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableProjectionExpectations;
+    /// # use rxpect::expectations::EqualityExpectations;
+    /// use rxpect::ExpectProjection;
+    ///
+    /// let names = vec!["foo", "bar"];
+    /// let lengths = vec![3, 3];
+    /// expect(names).zipped_with(lengths, |pair| {
+    ///     pair.projected_by(|(name, length)| name.len() == *length, |it| it.to_equal(true))
+    /// });
+    /// ```
+    fn zipped_with<D>(
+        self,
+        other: impl IntoIterator<Item = D>,
+        config: impl FnOnce(ExpectationList<'e, (C, D)>) -> ExpectationList<'e, (C, D)>,
+    ) -> Self
+    where
+        C: Clone,
+        D: Clone + Debug + 'e;
+
+    /// Add expectations that every item in the collection must pass.
+    ///
+    /// All failing items are reported, not just the first one.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{EqualityExpectations, IterableProjectionExpectations};
+    ///
+    /// expect(vec![2, 4, 6]).each_item(|it| it.to_not_equal(0));
+    /// ```
+    fn each_item(
+        self,
+        config: impl FnOnce(ExpectationList<'e, C>) -> ExpectationList<'e, C>,
+    ) -> Self;
+
+    /// Add expectations on the item at `index`.
+    ///
+    /// Fails if the collection has no item at that index.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{EqualityExpectations, IterableProjectionExpectations};
+    ///
+    /// expect(vec!["foo", "bar"]).at_index(1, |it| it.to_equal("bar"));
+    /// ```
+    fn at_index(
+        self,
+        index: usize,
+        config: impl FnOnce(ExpectationList<'e, C>) -> ExpectationList<'e, C>,
+    ) -> Self;
+
+    /// Add expectations on every item, with the expectations configured by the config at the
+    /// same position in `configs`.
+    ///
+    /// Fails if the number of items differs from the number of configs.
+    /// All failing items are reported, not just the first one.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{
+    /// #     EqualityExpectations, IterableProjectionExpectations, ItemConfig, StringExpectations,
+    /// # };
+    ///
+    /// let configs: Vec<ItemConfig<&str>> = vec![
+    ///     Box::new(|it| it.to_equal("GET")),
+    ///     Box::new(|it| it.to_contain("/users")),
+    /// ];
+    /// expect(vec!["GET", "/api/users/42"]).items_matching(configs);
+    /// ```
+    fn items_matching(self, configs: Vec<ItemConfig<'e, C>>) -> Self;
+}
+
+impl<'e, I, C, B> IterableProjectionExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Debug + 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn zipped_with<D>(
+        self,
+        other: impl IntoIterator<Item = D>,
+        config: impl FnOnce(ExpectationList<'e, (C, D)>) -> ExpectationList<'e, (C, D)>,
+    ) -> Self
+    where
+        C: Clone,
+        D: Clone + Debug + 'e,
+    {
+        self.to_pass(ZippedExpectations {
+            other: other.into_iter().collect(),
+            expectations: config(ExpectationList::new()),
+            _c: PhantomData,
+        })
+    }
+
+    fn each_item(
+        self,
+        config: impl FnOnce(ExpectationList<'e, C>) -> ExpectationList<'e, C>,
+    ) -> Self {
+        self.to_pass(EachItemExpectations(config(ExpectationList::new())))
+    }
+
+    fn at_index(
+        self,
+        index: usize,
+        config: impl FnOnce(ExpectationList<'e, C>) -> ExpectationList<'e, C>,
+    ) -> Self {
+        self.to_pass(AtIndexExpectations {
+            index,
+            expectations: config(ExpectationList::new()),
+        })
+    }
+
+    fn items_matching(self, configs: Vec<ItemConfig<'e, C>>) -> Self {
+        self.to_pass(ItemsMatchingExpectations(
+            configs
+                .into_iter()
+                .map(|config| config(ExpectationList::new()))
+                .collect(),
+        ))
+    }
+}
+
+/// Expectation for zipped_with
+struct ZippedExpectations<'e, C, D> {
+    other: Vec<D>,
+    expectations: ExpectationList<'e, (C, D)>,
+    _c: PhantomData<fn(&C)>,
+}
+
+impl<I, C, D> Expectation<I> for ZippedExpectations<'_, C, D>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Clone + Debug,
+    D: Clone + Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let length = value.into_iter().count();
+        if length != self.other.len() {
+            return CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected collections of equal length to zip, but they had {} and {} items\nactual: {:?}\n other: {:?}",
+                    length,
+                    self.other.len(),
+                    value,
+                    self.other
+                ))
+                .with_expected(format!("{} items", self.other.len()))
+                .with_actual(format!("{} items", length))
+                .with_matcher("zipped_with"),
+            );
+        }
+        collect_failures(
+            "zipped_with",
+            "pair",
+            value
+                .into_iter()
+                .zip(&self.other)
+                .map(|(a, b)| self.expectations.check(&(a.clone(), b.clone())))
+                .enumerate(),
+        )
+    }
+}
+
+/// Expectation for each_item
+struct EachItemExpectations<'e, C>(ExpectationList<'e, C>);
+
+impl<I, C> Expectation<I> for EachItemExpectations<'_, C>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        collect_failures(
+            "each_item",
+            "item",
+            value.into_iter().map(|item| self.0.check(item)).enumerate(),
+        )
+    }
+}
+
+/// Expectation for at_index
+struct AtIndexExpectations<'e, C> {
+    index: usize,
+    expectations: ExpectationList<'e, C>,
+}
+
+impl<I, C> Expectation<I> for AtIndexExpectations<'_, C>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let Some(item) = value.into_iter().nth(self.index) else {
+            return CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected iterable to have an item at index {}, but it had only {}\nactual: {:?}",
+                    self.index,
+                    value.into_iter().count(),
+                    value
+                ))
+                .with_expected(format!("an item at index {}", self.index))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("at_index"),
+            );
+        };
+        self.expectations.check(item).map_message(|message| {
+            format!(
+                "Expectations on item at index {} failed:\n{}",
+                self.index,
+                indent(message)
+            )
+        })
+    }
+}
+
+/// Expectation for items_matching
+struct ItemsMatchingExpectations<'e, C>(Vec<ExpectationList<'e, C>>);
+
+impl<I, C> Expectation<I> for ItemsMatchingExpectations<'_, C>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Debug,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let length = value.into_iter().count();
+        if length != self.0.len() {
+            return CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected {} items to match the configured expectations, but there were {}\nactual: {:?}",
+                    self.0.len(),
+                    length,
+                    value
+                ))
+                .with_expected(format!("{} items", self.0.len()))
+                .with_actual(format!("{} items", length))
+                .with_matcher("items_matching"),
+            );
+        }
+        collect_failures(
+            "items_matching",
+            "item",
+            value
+                .into_iter()
+                .zip(&self.0)
+                .map(|(item, expectations)| expectations.check(item))
+                .enumerate(),
+        )
+    }
+}
+
+/// Combine the results of checking the items of a collection, reporting every item that failed
+/// under a header naming its index. The failures of the items are kept as causes.
+fn collect_failures(
+    matcher: &'static str,
+    subject: &str,
+    results: impl Iterator<Item = (usize, CheckResult)>,
+) -> CheckResult {
+    let (indices, causes): (Vec<_>, Vec<_>) = results
+        .filter_map(|(index, result)| result.into_failure().map(|failure| (index, failure)))
+        .unzip();
+    if causes.is_empty() {
+        return CheckResult::Pass;
+    }
+    let message = indices
+        .iter()
+        .zip(&causes)
+        .map(|(index, failure)| {
+            format!(
+                "Expectations on {} at index {} failed:\n{}",
+                subject,
+                index,
+                indent(failure.message())
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    CheckResult::FailWith(
+        Failure::new(message)
+            .with_matcher(matcher)
+            .with_causes(causes),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_failures, ItemConfig, IterableProjectionExpectations};
+    use crate::expectations::{EqualityExpectations, NumberExpectations};
+    use crate::{expect, CheckResult, ExpectProjection, Failure};
+
+    #[test]
+    pub fn that_zipped_with_checks_every_pair() {
+        // Given two parallel collections
+        let (a, b) = (vec![1, 2, 3], vec![2, 4, 6]);
+
+        // Expect every pair to be checked
+        expect(a).zipped_with(b, |pair| {
+            pair.projected_by(|(a, b)| a * 2 == *b, |it| it.to_equal(true))
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "they had 2 and 3 items")]
+    pub fn that_zipped_with_does_not_accept_different_lengths() {
+        // Given two collections of different length
+        let (a, b) = (vec![1, 2], vec![1, 2, 3]);
+
+        // Expect zipped_with to fail
+        expect(a).zipped_with(b, |pair| pair);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expectations on pair at index 1 failed:\n  Expectation failed (expected != actual)\n    expected: `false`\n      actual: `false`\nExpectations on pair at index 2 failed:"
+    )]
+    pub fn that_zipped_with_reports_every_failing_pair() {
+        // Given two collections that differ from the second item
+        let (a, b) = (vec![1, 2, 3], vec![1, 5, 6]);
+
+        // Expect zipped_with to report every failing pair, indented
+        expect(a).zipped_with(b, |pair| {
+            pair.projected_by(|(a, b)| a == b, |it| it.to_not_equal(false))
+        });
+    }
+
+    #[test]
+    pub fn that_each_item_checks_every_item() {
+        // Given a collection of non-zero numbers
+        let values = vec![2, 4, 6];
+
+        // Expect every item to pass
+        expect(values).each_item(|it| it.to_not_equal(0));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expectations on item at index 1 failed:\n  Expectation failed (expected != actual)\n  expected: `0`\n    actual: `0`\nExpectations on item at index 3 failed:"
+    )]
+    pub fn that_each_item_reports_every_failing_item() {
+        // Given a collection with two zeroes
+        let values = vec![1, 0, 2, 0];
+
+        // Expect each_item to report both of them
+        expect(values).each_item(|it| it.to_not_equal(0));
+    }
+
+    #[test]
+    pub fn that_at_index_checks_the_item_at_the_index() {
+        // Given a collection
+        let values = vec!["foo", "bar", "baz"];
+
+        // Expect at_index to check the item at the index
+        expect(values).at_index(1, |it| it.to_equal("bar"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectations on item at index 2 failed:\n  Expectation failed")]
+    pub fn that_at_index_reports_failures_on_the_item() {
+        // Given a collection
+        let values = vec![1, 2, 3];
+
+        // Expect at_index to report the failing item, indented
+        expect(values).at_index(2, |it| it.to_equal(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected iterable to have an item at index 3, but it had only 2")]
+    pub fn that_at_index_does_not_accept_indices_out_of_range() {
+        // Given a collection with two items
+        let values = vec![1, 2];
+
+        // Expect at_index to fail for an index past the end
+        expect(values).at_index(3, |it| it);
+    }
+
+    #[test]
+    pub fn that_items_matching_checks_each_item_with_its_config() {
+        // Given different expectations for each position
+        let configs: Vec<ItemConfig<i32>> = vec![
+            Box::new(|it| it.to_be_positive()),
+            Box::new(|it| it.to_be_zero()),
+            Box::new(|it| it.to_be_negative().to_not_equal(-2)),
+        ];
+
+        // Expect every item to be checked with the config at its position
+        expect(vec![1, 0, -1]).items_matching(configs);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 1 items to match the configured expectations, but there were 2"
+    )]
+    pub fn that_items_matching_does_not_accept_different_lengths() {
+        // Given more items than configs
+        let configs: Vec<ItemConfig<i32>> = vec![Box::new(|it| it)];
+
+        // Expect items_matching to fail
+        expect(vec![1, 2]).items_matching(configs);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expectations on item at index 0 failed:\n  Expected 0 to be positive\nExpectations on item at index 2 failed:\n  Expectation failed (expected == actual)"
+    )]
+    pub fn that_items_matching_reports_every_failing_item() {
+        // Given configs that the first and last items don't pass
+        let configs: Vec<ItemConfig<i32>> = vec![
+            Box::new(|it| it.to_be_positive()),
+            Box::new(|it| it.to_be_positive()),
+            Box::new(|it| it.to_equal(4)),
+        ];
+
+        // Expect items_matching to report both of them, indented
+        expect(vec![0, 2, 3]).items_matching(configs);
+    }
+
+    #[test]
+    pub fn that_item_failures_are_kept_as_causes() {
+        // Given the results of checking three items, two of which failed
+        let results = vec![
+            (0, CheckResult::Fail("first".to_owned())),
+            (1, CheckResult::Pass),
+            (
+                2,
+                CheckResult::FailWith(Failure::new("third").with_matcher("to_equal")),
+            ),
+        ];
+
+        // When they are collected
+        let failure = collect_failures("each_item", "item", results.into_iter())
+            .into_failure()
+            .expect("the collection to fail");
+
+        // Then every failing item is reported and kept as a cause
+        assert_eq!(
+            "Expectations on item at index 0 failed:\n  first\nExpectations on item at index 2 failed:\n  third",
+            failure.message()
+        );
+        assert_eq!(
+            [
+                Failure::new("first"),
+                Failure::new("third").with_matcher("to_equal")
+            ],
+            failure.causes()
+        );
+        assert_eq!(Some("each_item"), failure.matcher());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use alloc::vec::Vec;
+use core::any::{type_name, Any};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// Extension trait for expectations on the concrete types of items in a collection of trait objects
+pub trait IterableTypeExpectations<'e, I, C> {
+    /// Expect every item to be of the concrete type `D`
+    ///
+    /// Items are downcast through their [AsRef<dyn Any>] implementation, which `Box<dyn Any>` provides.
+    /// For collections of other trait objects, implement `AsRef<dyn Any>` for `Box<dyn YourTrait>`,
+    /// for example by making `Any` a supertrait of your trait.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableTypeExpectations;
+    /// use std::any::Any;
+    ///
+    /// let plugins: Vec<Box<dyn Any>> = vec![Box::new(1u8), Box::new(2u8)];
+    /// expect(plugins).to_have_each_of_type::<u8>();
+    /// ```
+    fn to_have_each_of_type<D: Any>(self) -> Self;
+}
+
+impl<'e, I, C, B> IterableTypeExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: AsRef<dyn Any> + 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn to_have_each_of_type<D: Any>(self) -> Self {
+        self.to_pass(EachOfTypeExpectation::<C, D>(PhantomData))
+    }
+}
+
+/// Expectation for to_have_each_of_type
+struct EachOfTypeExpectation<C, D>(PhantomData<fn(&C) -> D>);
+
+impl<I, C, D> Expectation<I> for EachOfTypeExpectation<C, D>
+where
+    I: Debug,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: AsRef<dyn Any>,
+    D: Any,
+{
+    fn check(&self, value: &I) -> CheckResult {
+        let mismatches = value
+            .into_iter()
+            .enumerate()
+            .filter(|(_, item)| !item.as_ref().is::<D>())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        match mismatches.first() {
+            None => CheckResult::Pass,
+            Some(first) => CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected all items to be of type `{}`, but the first mismatch was at index {}\nmismatching indices: {:?}\n               items: {:?}",
+                    type_name::<D>(),
+                    first,
+                    mismatches,
+                    value
+                ))
+                .with_expected(type_name::<D>())
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_have_each_of_type"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IterableTypeExpectations;
+    use crate::expect;
+    use std::any::Any;
+
+    #[test]
+    pub fn that_to_have_each_of_type_accepts_items_of_the_type() {
+        // Given a collection of boxed values of the same type
+        let items: Vec<Box<dyn Any>> = vec![Box::new(1u8), Box::new(2u8)];
+
+        // Expect to_have_each_of_type to pass with that type
+        expect(items).to_have_each_of_type::<u8>();
+    }
+
+    #[test]
+    #[should_panic(expected = "first mismatch was at index 1")]
+    pub fn that_to_have_each_of_type_does_not_accept_other_types() {
+        // Given a collection of boxed values of different types
+        let items: Vec<Box<dyn Any>> = vec![Box::new(1u8), Box::new("foo"), Box::new(2u16)];
+
+        // Expect to_have_each_of_type to fail
+        expect(items).to_have_each_of_type::<u8>();
+    }
+
+    #[test]
+    pub fn that_to_have_each_of_type_works_with_other_trait_objects() {
+        // Given a trait with Any as supertrait
+        trait Plugin: Any + std::fmt::Debug {}
+        #[derive(Debug)]
+        struct MyPlugin;
+        impl Plugin for MyPlugin {}
+        impl AsRef<dyn Any> for Box<dyn Plugin> {
+            fn as_ref(&self) -> &dyn Any {
+                &**self
+            }
+        }
+
+        // And a collection of plugins
+        let plugins: Vec<Box<dyn Plugin>> = vec![Box::new(MyPlugin), Box::new(MyPlugin)];
+
+        // Expect to_have_each_of_type to pass with the concrete plugin type
+        expect(plugins).to_have_each_of_type::<MyPlugin>();
+    }
+}
@@ -0,0 +1,142 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on the uniqueness of items in a collection
+pub trait IterableUniquenessExpectations<'e, I, C> {
+    /// Expect no item to appear more than once in the collection
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableUniquenessExpectations;
+    ///
+    /// expect(vec![1, 2, 3]).to_have_unique_items();
+    /// ```
+    fn to_have_unique_items(self) -> Self
+    where
+        C: PartialEq;
+
+    /// Expect no two items in the collection to have the same key
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IterableUniquenessExpectations;
+    ///
+    /// expect(vec![(1, "foo"), (2, "bar")]).to_have_unique_items_by(|(id, _)| *id);
+    /// ```
+    fn to_have_unique_items_by<K, F>(self, key: F) -> Self
+    where
+        K: PartialEq + Debug + 'e,
+        F: Fn(&C) -> K + 'e;
+}
+
+impl<'e, I, C, B> IterableUniquenessExpectations<'e, I, C> for B
+where
+    I: Debug + 'e,
+    for<'a> &'a I: IntoIterator<Item = &'a C>,
+    C: Debug + 'e,
+    B: ExpectationBuilder<'e, I>,
+{
+    fn to_have_unique_items(self) -> Self
+    where
+        C: PartialEq,
+    {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_unique_items",
+            (),
+            |actual: &I, _| first_duplicate(&actual.into_iter().collect::<Vec<_>>()).is_none(),
+            |actual: &I, _| {
+                let items = actual.into_iter().collect::<Vec<_>>();
+                let (item, indices) = first_duplicate(&items).expect("a duplicate to exist");
+                duplicate_message(item, &indices, actual)
+            },
+        ))
+    }
+
+    fn to_have_unique_items_by<K, F>(self, key: F) -> Self
+    where
+        K: PartialEq + Debug + 'e,
+        F: Fn(&C) -> K + 'e,
+    {
+        self.to_pass(PredicateExpectation::with_expected(
+            "to_have_unique_items_by",
+            key,
+            |_| None,
+            |actual: &I, key: &F| {
+                first_duplicate(&actual.into_iter().map(key).collect::<Vec<_>>()).is_none()
+            },
+            |actual: &I, key: &F| {
+                let keys = actual.into_iter().map(key).collect::<Vec<_>>();
+                let (key, indices) = first_duplicate(&keys).expect("a duplicate to exist");
+                duplicate_message(key, &indices, actual)
+            },
+        ))
+    }
+}
+
+/// Find the first value that appears more than once, along with all indices it appears at
+fn first_duplicate<K: PartialEq>(values: &[K]) -> Option<(&K, Vec<usize>)> {
+    values.iter().enumerate().find_map(|(index, value)| {
+        let indices = values
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| *other == value)
+            .map(|(other_index, _)| other_index)
+            .collect::<Vec<_>>();
+        (indices.len() > 1 && indices[0] == index).then_some((value, indices))
+    })
+}
+
+fn duplicate_message(value: &impl Debug, indices: &[usize], actual: &impl Debug) -> String {
+    format!(
+        "Expected all items to be unique, but {:?} appeared at indices {:?}\nactual: {:?}",
+        value, indices, actual
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IterableUniquenessExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_have_unique_items_accepts_unique_items() {
+        // Given a collection without duplicates
+        let values = vec!["foo", "bar", "baz"];
+
+        // Expect to_have_unique_items to pass
+        expect(values).to_have_unique_items();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected all items to be unique, but 2 appeared at indices [1, 3, 4]"
+    )]
+    pub fn that_to_have_unique_items_does_not_accept_duplicates() {
+        // Given a collection with a value appearing three times
+        let values = vec![1, 2, 3, 2, 2];
+
+        // Expect to_have_unique_items to report every index of the first duplicate
+        expect(values).to_have_unique_items();
+    }
+
+    #[test]
+    pub fn that_to_have_unique_items_by_accepts_unique_keys() {
+        // Given a collection with duplicated names but unique ids
+        let values = vec![(1, "foo"), (2, "foo")];
+
+        // Expect to_have_unique_items_by to pass on the ids
+        expect(values).to_have_unique_items_by(|(id, _)| *id);
+    }
+
+    #[test]
+    #[should_panic(expected = "but \"foo\" appeared at indices [0, 1]")]
+    pub fn that_to_have_unique_items_by_does_not_accept_duplicate_keys() {
+        // Given a collection with duplicated names
+        let values = vec![(1, "foo"), (2, "foo")];
+
+        // Expect to_have_unique_items_by to fail on the names
+        expect(values).to_have_unique_items_by(|(_, name)| *name);
+    }
+}
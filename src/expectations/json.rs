@@ -0,0 +1,206 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use serde_json::Value;
+
+/// Extension trait for expectations on [serde_json::Value].
+///
+/// Fields are addressed with dotted paths such as `"user.addresses.0.city"`, where numeric
+/// segments index into arrays. The empty path refers to the value itself.
+///
+/// Requires the `json` feature.
+pub trait JsonExpectations<'e> {
+    /// Expect the value to have a field at `path`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::JsonExpectations;
+    /// use serde_json::json;
+    ///
+    /// expect(json!({"user": {"roles": ["admin"]}})).to_have_field("user.roles.0");
+    /// ```
+    fn to_have_field(self, path: &str) -> Self;
+
+    /// Expect the value to have a field at `path` that equals `value`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::JsonExpectations;
+    /// use serde_json::json;
+    ///
+    /// expect(json!({"user": {"name": "Alice", "age": 42}}))
+    ///     .to_have_field_equal("user.name", "Alice")
+    ///     .to_have_field_equal("user.age", 42);
+    /// ```
+    fn to_have_field_equal(self, path: &str, value: impl Into<Value>) -> Self;
+
+    /// Expect the value to be an array with exactly `length` items
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::JsonExpectations;
+    /// use serde_json::json;
+    ///
+    /// expect(json!([1, "two", null])).to_be_json_array_of_length(3);
+    /// ```
+    fn to_be_json_array_of_length(self, length: usize) -> Self;
+}
+
+impl<'e, B> JsonExpectations<'e> for B
+where
+    B: ExpectationBuilder<'e, Value>,
+{
+    fn to_have_field(self, path: &str) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_field",
+            path.to_owned(),
+            |actual: &Value, path| resolve(actual, path).is_some(),
+            |actual, path| {
+                format!(
+                    "Expected field \"{}\" to be present, but it was absent\nactual: {}",
+                    path, actual
+                )
+            },
+        ))
+    }
+
+    fn to_have_field_equal(self, path: &str, value: impl Into<Value>) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_field_equal",
+            (path.to_owned(), value.into()),
+            |actual: &Value, (path, expected)| resolve(actual, path) == Some(expected),
+            |actual, (path, expected)| match resolve(actual, path) {
+                Some(field) => format!(
+                    "Expected field \"{}\" to equal {}, but it was {}\nactual: {}",
+                    path, expected, field, actual
+                ),
+                None => format!(
+                    "Expected field \"{}\" to equal {}, but it was absent\nactual: {}",
+                    path, expected, actual
+                ),
+            },
+        ))
+    }
+
+    fn to_be_json_array_of_length(self, length: usize) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_json_array_of_length",
+            length,
+            |actual: &Value, length| actual.as_array().is_some_and(|a| a.len() == *length),
+            |actual, length| match actual.as_array() {
+                Some(array) => format!(
+                    "Expected a JSON array of length {}, but it had length {}\nactual: {}",
+                    length,
+                    array.len(),
+                    actual
+                ),
+                None => format!(
+                    "Expected a JSON array of length {}, but it was not an array\nactual: {}",
+                    length, actual
+                ),
+            },
+        ))
+    }
+}
+
+/// Resolve a dotted path against a value, returning `None` if any segment is absent
+fn resolve<'v>(value: &'v Value, path: &str) -> Option<&'v Value> {
+    if path.is_empty() {
+        return Some(value);
+    }
+    path.split('.')
+        .try_fold(value, |value, segment| match value {
+            Value::Object(fields) => fields.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve, JsonExpectations};
+    use crate::expect;
+    use serde_json::{json, Value};
+
+    fn user() -> Value {
+        json!({
+            "user": {
+                "name": "Alice",
+                "addresses": [{"city": "Gothenburg"}, {"city": "Malmö"}],
+                "nickname": null
+            }
+        })
+    }
+
+    #[test]
+    pub fn that_resolve_follows_objects_and_arrays() {
+        // Given a nested value
+        let value = user();
+
+        // Expect paths to resolve through objects and array indices
+        assert_eq!(Some(&value), resolve(&value, ""));
+        assert_eq!(
+            Some(&json!("Malmö")),
+            resolve(&value, "user.addresses.1.city")
+        );
+        assert_eq!(None, resolve(&value, "user.addresses.2"));
+        assert_eq!(None, resolve(&value, "user.name.first"));
+    }
+
+    #[test]
+    pub fn that_to_have_field_accepts_present_fields() {
+        // Expect present fields, including null ones, to pass
+        expect(user())
+            .to_have_field("user.addresses.0")
+            .to_have_field("user.nickname");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected field \"user.email\" to be present, but it was absent")]
+    pub fn that_to_have_field_does_not_accept_absent_fields() {
+        // Expect an absent field to fail
+        expect(user()).to_have_field("user.email");
+    }
+
+    #[test]
+    pub fn that_to_have_field_equal_accepts_equal_fields() {
+        // Expect fields to be compared with values convertible into JSON
+        expect(user())
+            .to_have_field_equal("user.addresses.0.city", "Gothenburg")
+            .to_have_field_equal("user.nickname", Value::Null)
+            .to_have_field_equal("user.addresses.1", json!({"city": "Malmö"}));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected field \"user.name\" to equal \"Bob\", but it was \"Alice\"\nactual: {\"user\":"
+    )]
+    pub fn that_to_have_field_equal_shows_the_resolved_field() {
+        // Expect a differing field to fail with the resolved and the full value
+        expect(user()).to_have_field_equal("user.name", "Bob");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected field \"user.age\" to equal 42, but it was absent")]
+    pub fn that_to_have_field_equal_does_not_accept_absent_fields() {
+        // Expect an absent field to fail
+        expect(user()).to_have_field_equal("user.age", 42);
+    }
+
+    #[test]
+    pub fn that_to_be_json_array_of_length_accepts_arrays_of_the_length() {
+        // Expect arrays of the given length to pass
+        expect(json!([])).to_be_json_array_of_length(0);
+        expect(json!([1, 2])).to_be_json_array_of_length(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a JSON array of length 1, but it had length 2")]
+    pub fn that_to_be_json_array_of_length_does_not_accept_other_lengths() {
+        // Expect an array of another length to fail
+        expect(json!([1, 2])).to_be_json_array_of_length(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a JSON array of length 1, but it was not an array")]
+    pub fn that_to_be_json_array_of_length_does_not_accept_other_values() {
+        // Expect an object to fail
+        expect(json!({"0": 1})).to_be_json_array_of_length(1);
+    }
+}
@@ -0,0 +1,159 @@
+use crate::expectation_list::ExpectationList;
+use crate::projection::indent;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use std::fmt::Debug;
+use std::sync::{Mutex, RwLock};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Locks that guard a value, i.e. [Mutex] and [RwLock].
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait Lock: private::Sealed + Debug {
+    /// The type of the guarded value
+    type Value: Debug;
+
+    /// Acquire the lock, call `f` with the guarded value and release the lock again.
+    ///
+    /// Returns an error if the lock was poisoned
+    #[doc(hidden)]
+    fn with_value<R>(&self, f: impl FnOnce(&Self::Value) -> R) -> Result<R, String>;
+}
+
+impl<T: Debug> private::Sealed for Mutex<T> {}
+impl<T: Debug> Lock for Mutex<T> {
+    type Value = T;
+
+    fn with_value<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, String> {
+        match self.lock() {
+            Ok(guard) => Ok(f(&guard)),
+            Err(_) => Err("Expected mutex to not be poisoned, but it was".to_owned()),
+        }
+    }
+}
+
+impl<T: Debug> private::Sealed for RwLock<T> {}
+impl<T: Debug> Lock for RwLock<T> {
+    type Value = T;
+
+    fn with_value<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, String> {
+        match self.read() {
+            Ok(guard) => Ok(f(&guard)),
+            Err(_) => Err("Expected lock to not be poisoned, but it was".to_owned()),
+        }
+    }
+}
+
+impl<L: Lock> private::Sealed for &L {}
+impl<L: Lock> Lock for &L {
+    type Value = L::Value;
+
+    fn with_value<R>(&self, f: impl FnOnce(&L::Value) -> R) -> Result<R, String> {
+        (*self).with_value(f)
+    }
+}
+
+/// Extension trait for expectations on values guarded by a [Lock]
+pub trait LockExpectations<'e, L: Lock> {
+    /// Add expectations on the value guarded by the lock.
+    ///
+    /// The lock is acquired when the expectations are checked and released again right after,
+    /// so it must not be held by the current thread at that point.
+    /// A poisoned lock is reported as a failure.
+    /// ```
+    /// # use rxpect::expect_ref;
+    /// # use rxpect::expectations::{EqualityExpectations, LockExpectations};
+    /// use std::sync::Mutex;
+    ///
+    /// let counter = Mutex::new(7);
+    /// expect_ref(&counter).holds(|value| value.to_equal(7));
+    /// ```
+    fn holds(
+        self,
+        config: impl FnOnce(ExpectationList<'e, L::Value>) -> ExpectationList<'e, L::Value>,
+    ) -> Self;
+}
+
+impl<'e, L, B> LockExpectations<'e, L> for B
+where
+    L: Lock + 'e,
+    L::Value: 'e,
+    B: ExpectationBuilder<'e, L>,
+{
+    fn holds(
+        self,
+        config: impl FnOnce(ExpectationList<'e, L::Value>) -> ExpectationList<'e, L::Value>,
+    ) -> Self {
+        self.to_pass(HoldsExpectation(config(ExpectationList::new())))
+    }
+}
+
+/// Expectation for holds
+struct HoldsExpectation<'e, T>(ExpectationList<'e, T>);
+
+impl<L: Lock> Expectation<L> for HoldsExpectation<'_, L::Value> {
+    fn check(&self, value: &L) -> CheckResult {
+        match value.with_value(|guarded| self.0.check(guarded)) {
+            Ok(result) => result.map_message(|message| {
+                format!(
+                    "Expectations on the value guarded by the lock failed:\n{}",
+                    indent(message)
+                )
+            }),
+            Err(message) => CheckResult::FailWith(Failure::new(message).with_matcher("holds")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LockExpectations;
+    use crate::expectations::EqualityExpectations;
+    use crate::{expect, expect_ref};
+    use std::sync::{Arc, Mutex, RwLock};
+
+    #[test]
+    pub fn that_holds_runs_expectations_on_the_guarded_value() {
+        // Given a mutex and a lock
+        let mutex = Mutex::new("foo");
+        let lock = RwLock::new(vec![1, 2]);
+
+        // Expect the guarded values to be checked
+        expect_ref(&mutex).holds(|it| it.to_equal("foo"));
+        expect(&lock).holds(|it| it.to_equal(vec![1, 2]));
+
+        // And the locks to be released afterwards
+        assert!(mutex.try_lock().is_ok());
+        assert!(lock.try_write().is_ok());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expectations on the value guarded by the lock failed:\n  Expectation failed (expected == actual)"
+    )]
+    pub fn that_holds_reports_failing_expectations() {
+        // Given a mutex
+        let mutex = Mutex::new(1);
+
+        // Expect holds to fail when the guarded value differs
+        expect(mutex).holds(|it| it.to_equal(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected mutex to not be poisoned, but it was")]
+    pub fn that_holds_reports_poisoned_locks() {
+        // Given a mutex that was poisoned by a panicking thread
+        let mutex = Arc::new(Mutex::new(1));
+        let poisoner = mutex.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poison");
+        })
+        .join();
+
+        // Expect holds to fail
+        expect(mutex.as_ref()).holds(|it| it.to_equal(1));
+    }
+}
@@ -0,0 +1,596 @@
+use crate::expectation_list::ExpectationList;
+use crate::expectations::predicate::PredicateExpectation;
+use crate::projection::indent;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// Extension trait for expectations on maps, such as [HashMap](std::collections::HashMap)
+/// and [BTreeMap](std::collections::BTreeMap)
+pub trait MapExpectations<'e, M, K, V> {
+    /// Expect the map to contain a key
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::MapExpectations;
+    /// use std::collections::HashMap;
+    ///
+    /// expect(HashMap::from([("foo", 1)])).to_contain_key("foo");
+    /// ```
+    fn to_contain_key(self, key: K) -> Self;
+
+    /// Expect the map to contain a key with a specific value
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::MapExpectations;
+    /// use std::collections::BTreeMap;
+    ///
+    /// expect(BTreeMap::from([("foo", 1)])).to_contain_entry("foo", 1);
+    /// ```
+    fn to_contain_entry(self, key: K, value: V) -> Self;
+
+    /// Expect the map to contain a key and add expectations on the value it maps to
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{EqualityExpectations, MapExpectations};
+    /// use std::collections::HashMap;
+    ///
+    /// expect(HashMap::from([("foo", 1)])).to_contain_key_and("foo", |value| value.to_equal(1));
+    /// ```
+    fn to_contain_key_and(
+        self,
+        key: K,
+        config: impl FnOnce(ExpectationList<'e, V>) -> ExpectationList<'e, V>,
+    ) -> Self;
+
+    /// Expect the map to not contain a key
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::MapExpectations;
+    /// use std::collections::HashMap;
+    ///
+    /// expect(HashMap::from([("foo", 1)])).to_not_contain_key("bar");
+    /// ```
+    fn to_not_contain_key(self, key: K) -> Self;
+
+    /// Expect the map to contain exactly `size` entries
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::MapExpectations;
+    /// use std::collections::HashMap;
+    ///
+    /// expect(HashMap::from([("foo", 1), ("bar", 2)])).to_have_size(2);
+    /// ```
+    fn to_have_size(self, size: usize) -> Self;
+
+    /// Add expectations on the entries of the map, sorted by key.
+    ///
+    /// This gives a deterministic order to assert on even for maps that iterate in arbitrary
+    /// order, such as [HashMap](std::collections::HashMap).
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{EqualityExpectations, MapExpectations};
+    /// use std::collections::HashMap;
+    ///
+    /// expect(HashMap::from([("foo", 1), ("bar", 2)]))
+    ///     .sorted_entries(|entries| entries.to_equal(vec![("bar", 2), ("foo", 1)]));
+    /// ```
+    fn sorted_entries(
+        self,
+        config: impl FnOnce(ExpectationList<'e, Vec<(K, V)>>) -> ExpectationList<'e, Vec<(K, V)>>,
+    ) -> Self
+    where
+        K: Ord + Clone,
+        V: Clone;
+
+    /// Expect the map to agree with another map on the value of every key they share,
+    /// so that they can be merged without conflicts
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::MapExpectations;
+    /// use std::collections::HashMap;
+    ///
+    /// let a = HashMap::from([("foo", 1), ("bar", 2)]);
+    /// let b = HashMap::from([("bar", 2), ("baz", 3)]);
+    /// expect(a).to_merge_consistently_with(b);
+    /// ```
+    fn to_merge_consistently_with(self, other: M) -> Self;
+
+    /// Expect the map to survive serializing to and deserializing from JSON with all entries intact.
+    ///
+    /// This catches problems with keys that can't be represented as JSON object keys.
+    /// Requires the `json` feature.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::MapExpectations;
+    /// use std::collections::BTreeMap;
+    ///
+    /// expect(BTreeMap::from([(1, true), (2, false)])).to_json_roundtrip_map();
+    /// ```
+    #[cfg(feature = "json")]
+    fn to_json_roundtrip_map(self) -> Self
+    where
+        M: serde::Serialize + serde::de::DeserializeOwned;
+}
+
+impl<'e, M, K, V, B> MapExpectations<'e, M, K, V> for B
+where
+    M: Debug + 'e,
+    for<'a> &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: PartialEq + Debug + 'e,
+    V: PartialEq + Debug + 'e,
+    B: ExpectationBuilder<'e, M>,
+{
+    fn to_contain_key(self, key: K) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_contain_key",
+            key,
+            |actual: &M, key: &K| get(actual, key).is_some(),
+            |actual: &M, key: &K| {
+                format!(
+                    "Expected map to contain key {:?}\nactual: {:?}",
+                    key, actual
+                )
+            },
+        ))
+    }
+
+    fn to_contain_entry(self, key: K, value: V) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_contain_entry",
+            (key, value),
+            |actual: &M, (key, value): &(K, V)| get(actual, key) == Some(value),
+            |actual: &M, (key, value): &(K, V)| match get(actual, key) {
+                Some(found) => format!(
+                    "Expected map to contain {:?}: {:?}, but the value was {:?}\nactual: {:?}",
+                    key, value, found, actual
+                ),
+                None => format!(
+                    "Expected map to contain {:?}: {:?}, but the key was missing\nactual: {:?}",
+                    key, value, actual
+                ),
+            },
+        ))
+    }
+
+    fn to_contain_key_and(
+        self,
+        key: K,
+        config: impl FnOnce(ExpectationList<'e, V>) -> ExpectationList<'e, V>,
+    ) -> Self {
+        self.to_pass(ContainKeyAndExpectation {
+            key,
+            expectations: config(ExpectationList::new()),
+            _m: PhantomData,
+        })
+    }
+
+    fn to_not_contain_key(self, key: K) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_not_contain_key",
+            key,
+            |actual: &M, key: &K| get(actual, key).is_none(),
+            |actual: &M, key: &K| {
+                format!(
+                    "Expected map to not contain key {:?}, but it mapped to {:?}\nactual: {:?}",
+                    key,
+                    get(actual, key).expect("key should be present"),
+                    actual
+                )
+            },
+        ))
+    }
+
+    fn to_have_size(self, size: usize) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_size",
+            size,
+            |actual: &M, size: &usize| actual.into_iter().count() == *size,
+            |actual: &M, size: &usize| {
+                format!(
+                    "Expected map to have {} entries, but it had {}\nactual: {:?}",
+                    size,
+                    actual.into_iter().count(),
+                    actual
+                )
+            },
+        ))
+    }
+
+    fn sorted_entries(
+        self,
+        config: impl FnOnce(ExpectationList<'e, Vec<(K, V)>>) -> ExpectationList<'e, Vec<(K, V)>>,
+    ) -> Self
+    where
+        K: Ord + Clone,
+        V: Clone,
+    {
+        self.to_pass(SortedEntriesExpectation {
+            expectations: config(ExpectationList::new()),
+            _m: PhantomData,
+        })
+    }
+
+    fn to_merge_consistently_with(self, other: M) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_merge_consistently_with",
+            other,
+            |actual: &M, other: &M| conflicts(actual, other).is_empty(),
+            |actual: &M, other: &M| {
+                let conflicts = conflicts(actual, other)
+                    .into_iter()
+                    .map(|(key, a, b)| format!("  {key:?}: {a:?} != {b:?}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!(
+                    "Expected maps to agree on all shared keys, but they had conflicts\n{}\nactual: {:?}\n other: {:?}",
+                    conflicts, actual, other
+                )
+            },
+        ))
+    }
+
+    #[cfg(feature = "json")]
+    fn to_json_roundtrip_map(self) -> Self
+    where
+        M: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        self.to_pass(JsonRoundTripExpectation)
+    }
+}
+
+/// Expectation for to_contain_key_and
+struct ContainKeyAndExpectation<'e, M, K, V> {
+    key: K,
+    expectations: ExpectationList<'e, V>,
+    _m: PhantomData<fn(&M)>,
+}
+
+impl<M, K, V> Expectation<M> for ContainKeyAndExpectation<'_, M, K, V>
+where
+    M: Debug,
+    for<'a> &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: PartialEq + Debug,
+    V: Debug,
+{
+    fn check(&self, value: &M) -> CheckResult {
+        match get(value, &self.key) {
+            None => CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected map to contain key {:?}\nactual: {:?}",
+                    self.key, value
+                ))
+                .with_expected(format!("{:?}", self.key))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_contain_key_and"),
+            ),
+            Some(found) => self.expectations.check(found).map_message(|message| {
+                format!(
+                    "Expectations on value for key {:?} failed:\n{}",
+                    self.key,
+                    indent(message)
+                )
+            }),
+        }
+    }
+}
+
+/// Expectation for sorted_entries
+struct SortedEntriesExpectation<'e, M, K, V> {
+    expectations: ExpectationList<'e, Vec<(K, V)>>,
+    _m: PhantomData<fn(&M)>,
+}
+
+impl<M, K, V> Expectation<M> for SortedEntriesExpectation<'_, M, K, V>
+where
+    M: Debug,
+    for<'a> &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Ord + Clone + Debug,
+    V: Clone + Debug,
+{
+    fn check(&self, value: &M) -> CheckResult {
+        let mut entries = value
+            .into_iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<Vec<_>>();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self.expectations.check(&entries).map_message(|message| {
+            format!(
+                "Expectations on entries sorted by key failed:\n{}\nsorted: {:?}",
+                indent(message),
+                entries
+            )
+        })
+    }
+}
+
+/// Expectation for to_json_roundtrip_map
+#[cfg(feature = "json")]
+struct JsonRoundTripExpectation;
+
+#[cfg(feature = "json")]
+impl<M, K, V> crate::Expectation<M> for JsonRoundTripExpectation
+where
+    M: serde::Serialize + serde::de::DeserializeOwned + Debug,
+    for<'a> &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: PartialEq + Debug,
+    V: PartialEq + Debug,
+{
+    fn check(&self, value: &M) -> crate::CheckResult {
+        let round_tripped = serde_json::to_string(value)
+            .map_err(|e| format!("serialization failed: {}", e))
+            .and_then(|json| {
+                serde_json::from_str::<M>(&json)
+                    .map_err(|e| format!("deserialization of {} failed: {}", json, e))
+            });
+        let round_tripped = match round_tripped {
+            Ok(round_tripped) => round_tripped,
+            Err(error) => {
+                return crate::CheckResult::FailWith(
+                    Failure::new(format!(
+                        "Expected map to round trip through JSON, but {}\nmap: {:?}",
+                        error, value
+                    ))
+                    .with_actual(format!("{:?}", value))
+                    .with_matcher("to_json_roundtrip_map"),
+                )
+            }
+        };
+        let lost = value
+            .into_iter()
+            .filter(|(key, _)| !round_tripped.into_iter().any(|(k, _)| k == *key))
+            .map(|(key, value)| format!("  lost {:?}: {:?}", key, value));
+        let added = round_tripped
+            .into_iter()
+            .filter(|(key, _)| !value.into_iter().any(|(k, _)| k == *key))
+            .map(|(key, value)| format!("  added {:?}: {:?}", key, value));
+        let differences = lost
+            .chain(added)
+            .chain(
+                conflicts(value, &round_tripped)
+                    .into_iter()
+                    .map(|(key, a, b)| format!("  changed {:?}: {:?} became {:?}", key, a, b)),
+            )
+            .collect::<Vec<_>>();
+        if differences.is_empty() {
+            crate::CheckResult::Pass
+        } else {
+            crate::CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected map to round trip through JSON, but entries differed\n{}\n  original: {:?}\nround trip: {:?}",
+                    differences.join("\n"),
+                    value,
+                    round_tripped
+                ))
+                .with_expected(format!("{:?}", value))
+                .with_actual(format!("{:?}", round_tripped))
+                .with_matcher("to_json_roundtrip_map"),
+            )
+        }
+    }
+}
+
+/// Find the value of a key by iterating over the map
+fn get<'a, M, K, V>(map: &'a M, key: &K) -> Option<&'a V>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: PartialEq + 'a,
+    V: 'a,
+{
+    map.into_iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+}
+
+/// Find all keys that are present in both maps but with different values
+fn conflicts<'a, M, K, V>(a: &'a M, b: &'a M) -> Vec<(&'a K, &'a V, &'a V)>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: PartialEq + 'a,
+    V: PartialEq + 'a,
+{
+    a.into_iter()
+        .filter_map(|(key, a_value)| {
+            b.into_iter()
+                .find(|(other_key, _)| *other_key == key)
+                .filter(|(_, b_value)| *b_value != a_value)
+                .map(|(_, b_value)| (key, a_value, b_value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MapExpectations;
+    use crate::expect;
+    use crate::expectations::EqualityExpectations;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    pub fn that_to_contain_key_accepts_present_keys() {
+        // Given a map
+        let map = HashMap::from([("foo", 1), ("bar", 2)]);
+
+        // Expect it to contain its keys
+        expect(map).to_contain_key("foo").to_contain_key("bar");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected map to contain key \"baz\"\nactual: {\"foo\": 1}")]
+    pub fn that_to_contain_key_does_not_accept_missing_keys() {
+        // Given a map
+        let map = BTreeMap::from([("foo", 1)]);
+
+        // Expect to_contain_key to fail for a missing key
+        expect(map).to_contain_key("baz");
+    }
+
+    #[test]
+    pub fn that_to_contain_entry_accepts_present_entries() {
+        // Given a map
+        let map = BTreeMap::from([("foo", 1), ("bar", 2)]);
+
+        // Expect it to contain its entries
+        expect(map)
+            .to_contain_entry("foo", 1)
+            .to_contain_entry("bar", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected map to contain \"foo\": 2, but the value was 1")]
+    pub fn that_to_contain_entry_does_not_accept_other_values() {
+        // Given a map
+        let map = HashMap::from([("foo", 1)]);
+
+        // Expect to_contain_entry to fail for another value
+        expect(map).to_contain_entry("foo", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected map to contain \"bar\": 1, but the key was missing")]
+    pub fn that_to_contain_entry_does_not_accept_missing_keys() {
+        // Given a map
+        let map = HashMap::from([("foo", 1)]);
+
+        // Expect to_contain_entry to fail for a missing key
+        expect(map).to_contain_entry("bar", 1);
+    }
+
+    #[test]
+    pub fn that_to_contain_key_and_checks_the_value() {
+        // Given a map
+        let map = HashMap::from([("foo", 1), ("bar", 2)]);
+
+        // Expect the expectations to run against the value of the key
+        expect(map).to_contain_key_and("bar", |value| value.to_equal(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectations on value for key \"foo\" failed:")]
+    pub fn that_to_contain_key_and_reports_failures_on_the_value() {
+        // Given a map
+        let map = BTreeMap::from([("foo", 1)]);
+
+        // Expect to_contain_key_and to fail when the value doesn't pass
+        expect(map).to_contain_key_and("foo", |value| value.to_equal(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected map to contain key \"bar\"\nactual: {\"foo\": 1}")]
+    pub fn that_to_contain_key_and_does_not_accept_missing_keys() {
+        // Given a map
+        let map = BTreeMap::from([("foo", 1)]);
+
+        // Expect to_contain_key_and to fail for a missing key
+        expect(map).to_contain_key_and("bar", |value| value.to_equal(1));
+    }
+
+    #[test]
+    pub fn that_sorted_entries_sorts_by_key() {
+        // Given a map with arbitrary iteration order
+        let map = HashMap::from([(3, "c"), (1, "a"), (2, "b")]);
+
+        // Expect the entries to be sorted by key
+        expect(map).sorted_entries(|entries| entries.to_equal(vec![(1, "a"), (2, "b"), (3, "c")]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectations on entries sorted by key failed:")]
+    pub fn that_sorted_entries_reports_failures_on_the_sorted_entries() {
+        // Given a map
+        let map = HashMap::from([(2, "b"), (1, "a")]);
+
+        // Expect sorted_entries to fail when the entries don't match
+        expect(map).sorted_entries(|entries| entries.to_equal(vec![(2, "b"), (1, "a")]));
+    }
+
+    #[test]
+    pub fn that_to_not_contain_key_accepts_missing_keys() {
+        // Given a map
+        let map = HashMap::from([("foo", 1)]);
+
+        // Expect it to not contain another key
+        expect(map).to_not_contain_key("bar");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected map to not contain key \"foo\", but it mapped to 1")]
+    pub fn that_to_not_contain_key_does_not_accept_present_keys() {
+        // Given a map
+        let map = BTreeMap::from([("foo", 1)]);
+
+        // Expect to_not_contain_key to fail for a present key
+        expect(map).to_not_contain_key("foo");
+    }
+
+    #[test]
+    pub fn that_to_have_size_accepts_the_number_of_entries() {
+        // Given a map with two entries
+        let map = HashMap::from([("foo", 1), ("bar", 2)]);
+
+        // Expect it to have size two
+        expect(map).to_have_size(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected map to have 1 entries, but it had 0")]
+    pub fn that_to_have_size_does_not_accept_other_sizes() {
+        // Given an empty map
+        let map = BTreeMap::<&str, i32>::new();
+
+        // Expect to_have_size to fail
+        expect(map).to_have_size(1);
+    }
+
+    #[test]
+    pub fn that_to_merge_consistently_with_accepts_agreeing_maps() {
+        // Given two maps that agree on their shared key
+        let a = HashMap::from([("foo", 1), ("bar", 2)]);
+        let b = HashMap::from([("bar", 2), ("baz", 3)]);
+
+        // Expect to_merge_consistently_with to pass
+        expect(a).to_merge_consistently_with(b);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"bar\": 2 != 3")]
+    pub fn that_to_merge_consistently_with_does_not_accept_conflicts() {
+        // Given two maps that disagree on a shared key
+        let a = BTreeMap::from([("foo", 1), ("bar", 2)]);
+        let b = BTreeMap::from([("bar", 3)]);
+
+        // Expect to_merge_consistently_with to fail
+        expect(a).to_merge_consistently_with(b);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    pub fn that_to_json_roundtrip_map_accepts_maps_with_integer_keys() {
+        // Given a map with integer keys
+        let map = HashMap::from([(1, "one".to_string()), (2, "two".to_string())]);
+
+        // Expect it to round trip through JSON
+        expect(map).to_json_roundtrip_map();
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    #[should_panic(expected = "key must be a string")]
+    pub fn that_to_json_roundtrip_map_does_not_accept_unrepresentable_keys() {
+        // Given a map with tuple keys
+        let map = BTreeMap::from([((1, 2), "one".to_string())]);
+
+        // Expect it to fail to round trip through JSON
+        expect(map).to_json_roundtrip_map();
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    #[should_panic(expected = "changed \"value\": Some(NaN) became None")]
+    pub fn that_to_json_roundtrip_map_reports_changed_entries() {
+        // Given a map with a value that JSON can't represent
+        let map = BTreeMap::from([("value".to_string(), Some(f64::NAN))]);
+
+        // Expect the round trip to report the changed entry
+        expect(map).to_json_roundtrip_map();
+    }
+}
@@ -1,2 +1,61 @@
+mod bytes;
+mod char;
+mod cow;
+mod deque;
+mod deref;
+mod duration;
 mod equality;
+#[cfg(feature = "float")]
+mod float;
+mod identity;
+mod iterables;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "std")]
+mod lock;
+mod map;
+#[cfg(feature = "std")]
+mod named;
+mod number;
+mod option;
+mod order;
+#[cfg(feature = "std")]
+mod path;
+mod predicate;
+mod result;
+mod round_trip;
+mod satisfy;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+mod string;
+mod vec;
+pub use bytes::*;
+pub use char::*;
+pub use cow::*;
+pub use deque::*;
+pub use deref::*;
+pub use duration::*;
 pub use equality::*;
+#[cfg(feature = "float")]
+pub use float::*;
+pub use identity::*;
+pub use iterables::*;
+#[cfg(feature = "json")]
+pub use json::*;
+#[cfg(feature = "std")]
+pub use lock::*;
+pub use map::*;
+#[cfg(feature = "std")]
+pub use named::*;
+pub use number::*;
+pub use option::*;
+pub use order::*;
+#[cfg(feature = "std")]
+pub use path::*;
+pub use result::*;
+pub use round_trip::*;
+pub use satisfy::*;
+#[cfg(feature = "snapshot")]
+pub use snapshot::*;
+pub use string::*;
+pub use vec::*;
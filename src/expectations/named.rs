@@ -0,0 +1,85 @@
+use crate::registry::{lookup_matcher, Lookup};
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use std::any::type_name;
+use std::fmt::Debug;
+
+/// Extension trait for expectations using matchers from the registry
+pub trait NamedMatcherExpectations<'e, T> {
+    /// Expect the value to satisfy a matcher registered with [register_matcher](crate::register_matcher)
+    ///
+    /// The matcher is looked up by name when the expectation is checked.
+    /// ```
+    /// # use rxpect::{expect, register_matcher};
+    /// # use rxpect::expectations::NamedMatcherExpectations;
+    ///
+    /// register_matcher("non_empty", |v: &String| !v.is_empty());
+    /// expect(String::from("foo")).to_satisfy_named("non_empty");
+    /// ```
+    fn to_satisfy_named(self, name: &'e str) -> Self;
+}
+
+impl<'e, T, B> NamedMatcherExpectations<'e, T> for B
+where
+    T: Debug + 'static,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_satisfy_named(self, name: &'e str) -> Self {
+        self.to_pass(NamedMatcherExpectation(name))
+    }
+}
+
+/// Expectation for to_satisfy_named
+struct NamedMatcherExpectation<'e>(&'e str);
+
+impl<T: Debug + 'static> Expectation<T> for NamedMatcherExpectation<'_> {
+    fn check(&self, value: &T) -> CheckResult {
+        let message = match lookup_matcher::<T>(self.0) {
+            Lookup::Found(matcher) if matcher.matches(value) => return CheckResult::Pass,
+            Lookup::Found(_) => format!("Expected {:?} to satisfy matcher `{}`", value, self.0),
+            Lookup::WrongType => format!(
+                "matcher registered as `{}` does not accept values of type `{}`",
+                self.0,
+                type_name::<T>()
+            ),
+            Lookup::Missing => format!("no matcher registered as `{}`", self.0),
+        };
+        CheckResult::FailWith(
+            Failure::new(message)
+                .with_expected(self.0)
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_satisfy_named"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NamedMatcherExpectations;
+    use crate::{expect, register_matcher};
+
+    #[test]
+    pub fn that_to_satisfy_named_accepts_matching_values() {
+        // Given a registered matcher
+        register_matcher("named_test_positive_even", |v: &i32| *v > 0 && v % 2 == 0);
+
+        // Expect a matching value to pass
+        expect(4).to_satisfy_named("named_test_positive_even");
+    }
+
+    #[test]
+    #[should_panic(expected = "to satisfy matcher `named_test_negative`")]
+    pub fn that_to_satisfy_named_does_not_accept_other_values() {
+        // Given a registered matcher
+        register_matcher("named_test_negative", |v: &i32| *v < 0);
+
+        // Expect a value that doesn't match to fail
+        expect(4).to_satisfy_named("named_test_negative");
+    }
+
+    #[test]
+    #[should_panic(expected = "no matcher registered as `named_test_missing`")]
+    pub fn that_to_satisfy_named_fails_for_unknown_names() {
+        // Expect an unknown matcher name to fail
+        expect(4).to_satisfy_named("named_test_missing");
+    }
+}
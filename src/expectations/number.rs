@@ -0,0 +1,612 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use alloc::string::ToString;
+use core::any::type_name;
+use core::fmt::{Debug, Display};
+use core::marker::PhantomData;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The primitive numeric types.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait Number: private::Sealed + Copy + PartialOrd + Debug + Display {
+    #[doc(hidden)]
+    const ZERO: Self;
+    #[doc(hidden)]
+    fn as_f64(self) -> f64;
+    /// The band of values within `percent` percent of the value, computed in the type itself
+    /// so that large integers don't lose precision
+    #[doc(hidden)]
+    fn percent_band(self, percent: f64) -> (Self, Self);
+}
+
+/// The primitive integer types.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+pub trait Integer: Number {
+    #[doc(hidden)]
+    fn is_even(self) -> bool;
+    #[doc(hidden)]
+    fn remainder(self, divisor: Self) -> Self;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*; |$value:ident, $percent:ident| $band:expr) => {
+        $(
+            impl private::Sealed for $t {}
+            impl Number for $t {
+                const ZERO: Self = 0 as $t;
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+                fn percent_band(self, $percent: f64) -> (Self, Self) {
+                    let $value = self;
+                    $band
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        // Float to integer casts saturate, so deviations beyond the type are clamped
+        impl_number!($($t),*; |value, percent| {
+            let deviation = ((value as f64).abs() * percent / 100.0) as Self;
+            (value.saturating_sub(deviation), value.saturating_add(deviation))
+        });
+        $(
+            impl Integer for $t {
+                fn is_even(self) -> bool {
+                    self % 2 == 0
+                }
+                fn remainder(self, divisor: Self) -> Self {
+                    self.wrapping_rem(divisor)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_number!(f32, f64; |value, percent| {
+    let deviation = value.abs() * percent as Self / 100.0;
+    (value - deviation, value + deviation)
+});
+
+/// Extension trait for expectations on all numbers
+pub trait NumberExpectations<'e, T> {
+    /// Expect the value to equal `expected` after being clamped to `[min, max]`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::NumberExpectations;
+    ///
+    /// expect(120).to_clamp_to(0, 100, 100);
+    /// expect(0.5).to_clamp_to(0.0, 1.0, 0.5);
+    /// ```
+    fn to_clamp_to(self, min: T, max: T, expected: T) -> Self;
+
+    /// Expect the value to be greater than zero
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::NumberExpectations;
+    ///
+    /// expect(0.5).to_be_positive();
+    /// ```
+    fn to_be_positive(self) -> Self;
+
+    /// Expect the value to be less than zero
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::NumberExpectations;
+    ///
+    /// expect(-3).to_be_negative();
+    /// ```
+    fn to_be_negative(self) -> Self;
+
+    /// Expect the value to be zero
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::NumberExpectations;
+    ///
+    /// expect(0u8).to_be_zero();
+    /// ```
+    fn to_be_zero(self) -> Self;
+
+    /// Expect the value to be within `percent` percent of `expected`,
+    /// i.e. `|actual - expected| <= |expected| * percent / 100`.
+    ///
+    /// The band scales with `expected`, so when `expected` is zero the value must be exactly zero.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::NumberExpectations;
+    ///
+    /// expect(1040).to_be_within_percent(1000, 5.0);
+    /// expect(0.0095).to_be_within_percent(0.01, 5.0);
+    /// ```
+    fn to_be_within_percent(self, expected: T, percent: f64) -> Self;
+}
+
+impl<'e, T, B> NumberExpectations<'e, T> for B
+where
+    T: Number + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_clamp_to(self, min: T, max: T, expected: T) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_clamp_to",
+            (min, max, expected),
+            |actual: &T, (min, max, expected)| clamp(*actual, *min, *max) == *expected,
+            |actual, (min, max, expected)| {
+                format!(
+                    "Expected {} clamped to [{}, {}] to be {}, but it was {}",
+                    actual,
+                    min,
+                    max,
+                    expected,
+                    clamp(*actual, *min, *max)
+                )
+            },
+        ))
+    }
+
+    fn to_be_positive(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_positive",
+            (),
+            |actual: &T, _| *actual > T::ZERO,
+            |actual, _| format!("Expected {} to be positive", actual),
+        ))
+    }
+
+    fn to_be_negative(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_negative",
+            (),
+            |actual: &T, _| *actual < T::ZERO,
+            |actual, _| format!("Expected {} to be negative", actual),
+        ))
+    }
+
+    fn to_be_zero(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_zero",
+            (),
+            |actual: &T, _| *actual == T::ZERO,
+            |actual, _| format!("Expected {} to be zero", actual),
+        ))
+    }
+
+    fn to_be_within_percent(self, expected: T, percent: f64) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_within_percent",
+            (expected, percent),
+            |actual: &T, (expected, percent)| {
+                let (low, high) = expected.percent_band(*percent);
+                (low..=high).contains(actual)
+            },
+            |actual, (expected, percent)| {
+                let (low, high) = expected.percent_band(*percent);
+                format!(
+                    "Expected {} to be within {}% of {}, but it was outside the allowed band [{}, {}]",
+                    actual, percent, expected, low, high
+                )
+            },
+        ))
+    }
+}
+
+fn clamp<T: Number>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Extension trait for integer expectations
+pub trait IntegerExpectations<'e, T> {
+    /// Expect the value to be losslessly convertible into the integer type `I`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IntegerExpectations;
+    ///
+    /// expect(255i32).to_fit_in::<u8>();
+    /// ```
+    fn to_fit_in<I>(self) -> Self
+    where
+        I: TryFrom<T> + 'e,
+        <I as TryFrom<T>>::Error: Display;
+
+    /// Expect the value to have `count` digits in its base 10 representation.
+    ///
+    /// The sign of negative numbers is not counted, and zero has one digit.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IntegerExpectations;
+    ///
+    /// expect(-1234).to_have_digit_count(4);
+    /// ```
+    fn to_have_digit_count(self, count: usize) -> Self;
+
+    /// Expect the value to be even
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IntegerExpectations;
+    ///
+    /// expect(-4).to_be_even();
+    /// ```
+    fn to_be_even(self) -> Self;
+
+    /// Expect the value to be odd
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IntegerExpectations;
+    ///
+    /// expect(7u64).to_be_odd();
+    /// ```
+    fn to_be_odd(self) -> Self;
+
+    /// Expect the value to be divisible by `divisor`, i.e. to leave no remainder.
+    ///
+    /// Nothing is considered divisible by zero.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IntegerExpectations;
+    ///
+    /// expect(4096usize).to_be_divisible_by(512);
+    /// ```
+    fn to_be_divisible_by(self, divisor: T) -> Self;
+
+    /// Expect the value to be a multiple of `base`.
+    ///
+    /// This is the same as [to_be_divisible_by](IntegerExpectations::to_be_divisible_by), so
+    /// nothing is considered a multiple of zero.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::IntegerExpectations;
+    ///
+    /// expect(-12).to_be_multiple_of(4);
+    /// ```
+    fn to_be_multiple_of(self, base: T) -> Self;
+}
+
+impl<'e, T, B> IntegerExpectations<'e, T> for B
+where
+    T: Integer + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_fit_in<I>(self) -> Self
+    where
+        I: TryFrom<T> + 'e,
+        <I as TryFrom<T>>::Error: Display,
+    {
+        self.to_pass(FitInExpectation::<I>(PhantomData))
+    }
+
+    fn to_have_digit_count(self, count: usize) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_digit_count",
+            count,
+            |actual: &T, count| digit_count(actual) == *count,
+            |actual, count| {
+                format!(
+                    "Expected {} to have {} digits, but it had {}",
+                    actual,
+                    count,
+                    digit_count(actual)
+                )
+            },
+        ))
+    }
+
+    fn to_be_even(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_even",
+            (),
+            |actual: &T, _| actual.is_even(),
+            |actual, _| format!("Expected {} to be even", actual),
+        ))
+    }
+
+    fn to_be_odd(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_odd",
+            (),
+            |actual: &T, _| !actual.is_even(),
+            |actual, _| format!("Expected {} to be odd", actual),
+        ))
+    }
+
+    fn to_be_divisible_by(self, divisor: T) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_divisible_by",
+            divisor,
+            |actual: &T, divisor| is_divisible(*actual, *divisor),
+            |actual, divisor| {
+                if *divisor == T::ZERO {
+                    format!(
+                        "Expected {} to be divisible by {}, but the divisor is zero",
+                        actual, divisor
+                    )
+                } else {
+                    format!(
+                        "Expected {} to be divisible by {}, but the remainder was {}",
+                        actual,
+                        divisor,
+                        actual.remainder(*divisor)
+                    )
+                }
+            },
+        ))
+    }
+
+    fn to_be_multiple_of(self, base: T) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_multiple_of",
+            base,
+            |actual: &T, base| is_divisible(*actual, *base),
+            |actual, base| {
+                if *base == T::ZERO {
+                    format!(
+                        "Expected {} to be a multiple of {}, but the base is zero",
+                        actual, base
+                    )
+                } else {
+                    format!(
+                        "Expected {} to be a multiple of {}, but the remainder was {}",
+                        actual,
+                        base,
+                        actual.remainder(*base)
+                    )
+                }
+            },
+        ))
+    }
+}
+
+/// Whether `value` is divisible by `divisor`, without panicking on a zero divisor
+fn is_divisible<T: Integer>(value: T, divisor: T) -> bool {
+    divisor != T::ZERO && value.remainder(divisor) == T::ZERO
+}
+
+fn digit_count<T: Integer>(value: &T) -> usize {
+    value.to_string().trim_start_matches('-').len()
+}
+
+/// Expectation for to_fit_in
+struct FitInExpectation<I>(PhantomData<fn() -> I>);
+
+impl<T, I> Expectation<T> for FitInExpectation<I>
+where
+    T: Integer,
+    I: TryFrom<T>,
+    <I as TryFrom<T>>::Error: Display,
+{
+    fn check(&self, value: &T) -> CheckResult {
+        match I::try_from(*value) {
+            Ok(_) => CheckResult::Pass,
+            Err(error) => CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected {} ({}) to fit in {}, but the conversion failed: {}",
+                    value,
+                    type_name::<T>(),
+                    type_name::<I>(),
+                    error
+                ))
+                .with_expected(type_name::<I>())
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_fit_in"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntegerExpectations, NumberExpectations};
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_clamp_to_clamps_values() {
+        // Expect values below, inside and above the range to be clamped
+        expect(-5).to_clamp_to(0, 10, 0);
+        expect(5).to_clamp_to(0, 10, 5);
+        expect(15.0).to_clamp_to(0.0, 10.0, 10.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 15 clamped to [0, 10] to be 15, but it was 10")]
+    pub fn that_to_clamp_to_reports_the_clamped_value() {
+        // Given a value above the range
+        let value = 15u8;
+
+        // Expect to_clamp_to to fail when expecting the raw value
+        expect(value).to_clamp_to(0, 10, 15);
+    }
+
+    #[test]
+    pub fn that_sign_expectations_accept_matching_values() {
+        // Expect the sign of integers and floats to be checked
+        expect(1u8).to_be_positive();
+        expect(-0.5).to_be_negative();
+        expect(0i64).to_be_zero();
+        expect(-0.0f32).to_be_zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 0 to be positive")]
+    pub fn that_to_be_positive_does_not_accept_zero() {
+        // Expect zero to not be positive
+        expect(0).to_be_positive();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected NaN to be negative")]
+    pub fn that_to_be_negative_does_not_accept_nan() {
+        // Expect NaN to not be negative
+        expect(f64::NAN).to_be_negative();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 0.1 to be zero")]
+    pub fn that_to_be_zero_does_not_accept_other_values() {
+        // Expect a small value to not be zero
+        expect(0.1).to_be_zero();
+    }
+
+    #[test]
+    pub fn that_to_be_within_percent_accepts_values_in_the_band() {
+        // Expect values inside and on the edge of the band to pass
+        expect(105u32).to_be_within_percent(100, 5.0);
+        expect(-95).to_be_within_percent(-100, 5.0);
+        expect(2.5e9).to_be_within_percent(2.4e9, 5.0);
+        expect(0).to_be_within_percent(0, 10.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 106 to be within 5% of 100, but it was outside the allowed band [95, 105]"
+    )]
+    pub fn that_to_be_within_percent_reports_the_allowed_band() {
+        // Expect a value outside the band to fail
+        expect(106).to_be_within_percent(100, 5.0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected 18446744073709551615 to be within 0% of 18446744073709550615, but it was outside the allowed band [18446744073709550615, 18446744073709550615]"
+    )]
+    pub fn that_to_be_within_percent_does_not_lose_precision_for_large_integers() {
+        // Expect large integers to be compared exactly
+        expect(u64::MAX).to_be_within_percent(u64::MAX - 1000, 0.0);
+    }
+
+    #[test]
+    pub fn that_to_be_within_percent_clamps_the_band_to_the_type() {
+        // Expect a band reaching beyond the type to be clamped instead of overflowing
+        expect(u8::MAX).to_be_within_percent(200, 50.0);
+        expect(i8::MIN).to_be_within_percent(-100, 200.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the allowed band [0, 0]")]
+    pub fn that_to_be_within_percent_requires_exact_equality_for_zero() {
+        // Expect any deviation from zero to fail
+        expect(0.001).to_be_within_percent(0.0, 50.0);
+    }
+
+    #[test]
+    pub fn that_parity_expectations_accept_matching_values() {
+        // Expect the parity of positive and negative integers to be checked
+        expect(0).to_be_even();
+        expect(-4i8).to_be_even();
+        expect(-3).to_be_odd();
+        expect(u128::MAX).to_be_odd();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected -3 to be even")]
+    pub fn that_to_be_even_does_not_accept_odd_values() {
+        // Expect an odd value to not be even
+        expect(-3).to_be_even();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 2 to be odd")]
+    pub fn that_to_be_odd_does_not_accept_even_values() {
+        // Expect an even value to not be odd
+        expect(2usize).to_be_odd();
+    }
+
+    #[test]
+    pub fn that_divisibility_expectations_accept_divisible_values() {
+        // Expect zero, negative values and the minimum value to be checked
+        expect(0).to_be_divisible_by(7);
+        expect(-12).to_be_divisible_by(-4).to_be_multiple_of(3);
+        expect(i32::MIN).to_be_divisible_by(-1);
+        expect(u8::MAX).to_be_multiple_of(u8::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 4100 to be divisible by 512, but the remainder was 4")]
+    pub fn that_to_be_divisible_by_reports_the_remainder() {
+        // Expect an unaligned value to fail with the remainder
+        expect(4100).to_be_divisible_by(512);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 0 to be divisible by 0, but the divisor is zero")]
+    pub fn that_to_be_divisible_by_does_not_accept_zero_divisors() {
+        // Expect a zero divisor to fail rather than panic on the modulo
+        expect(0u32).to_be_divisible_by(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected -7 to be a multiple of 3, but the remainder was -1")]
+    pub fn that_to_be_multiple_of_reports_the_remainder() {
+        // Expect a value that isn't a multiple to fail with the remainder
+        expect(-7i64).to_be_multiple_of(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 5 to be a multiple of 0, but the base is zero")]
+    pub fn that_to_be_multiple_of_does_not_accept_zero_bases() {
+        // Expect a zero base to fail rather than panic on the modulo
+        expect(5).to_be_multiple_of(0);
+    }
+
+    #[test]
+    pub fn that_to_fit_in_accepts_values_in_range() {
+        // Given values at the bounds of u8
+        let (low, high) = (0i64, 255i64);
+
+        // Expect both to fit in an u8
+        expect(low).to_fit_in::<u8>();
+        expect(high).to_fit_in::<u8>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 256 (i32) to fit in u8")]
+    pub fn that_to_fit_in_does_not_accept_values_out_of_range() {
+        // Given a value just above the range of u8
+        let value = 256i32;
+
+        // Expect to_fit_in to fail
+        expect(value).to_fit_in::<u8>();
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn that_to_fit_in_does_not_accept_negative_values_for_unsigned_types() {
+        // Given a negative value
+        let value = -1i8;
+
+        // Expect to_fit_in to fail for an unsigned type
+        expect(value).to_fit_in::<u64>();
+    }
+
+    #[test]
+    pub fn that_to_have_digit_count_counts_digits() {
+        // Expect the digit count to ignore the sign and count zero as one digit
+        expect(12345u32).to_have_digit_count(5);
+        expect(-987i64).to_have_digit_count(3);
+        expect(0).to_have_digit_count(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected -100 to have 4 digits, but it had 3")]
+    pub fn that_to_have_digit_count_does_not_accept_other_counts() {
+        // Given a negative value
+        let value = -100;
+
+        // Expect to_have_digit_count to fail when counting the sign
+        expect(value).to_have_digit_count(4);
+    }
+}
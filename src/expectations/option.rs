@@ -0,0 +1,130 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::{ExpectationBuilder, RootExpectations};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on [Option] values
+pub trait OptionExpectations<'e, T> {
+    /// Expect the value to be `None`, or `Some` with a value matching `predicate`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::OptionExpectations;
+    ///
+    /// let timeout: Option<u32> = None;
+    /// expect(timeout).to_be_none_or_matching(|seconds| *seconds > 0);
+    /// expect(Some(30)).to_be_none_or_matching(|seconds| *seconds > 0);
+    /// ```
+    fn to_be_none_or_matching(self, predicate: impl Fn(&T) -> bool + 'e) -> Self;
+}
+
+impl<'e, T, B> OptionExpectations<'e, T> for B
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, Option<T>>,
+{
+    fn to_be_none_or_matching(self, predicate: impl Fn(&T) -> bool + 'e) -> Self {
+        self.to_pass(PredicateExpectation::with_expected(
+            "to_be_none_or_matching",
+            predicate,
+            |_| None,
+            |actual: &Option<T>, predicate| actual.as_ref().is_none_or(predicate),
+            |actual, _| {
+                format!(
+                    "Expected None or Some matching the predicate, but was {:?} which does not match",
+                    actual
+                )
+            },
+        ))
+    }
+}
+
+impl<T> RootExpectations<'_, Option<T>>
+where
+    T: Debug,
+{
+    /// Expect the value to be `Some` and return the value inside it.
+    ///
+    /// Any expectations already added are checked first, and this panics immediately if any of
+    /// them fail or if the value is `None`.
+    /// The value is moved out of the expectations, so nothing is checked again when they are dropped.
+    /// ```
+    /// # use rxpect::expect;
+    ///
+    /// let value = expect(Some(7)).unwrap_some();
+    /// assert_eq!(value, 7);
+    /// ```
+    #[track_caller]
+    pub fn unwrap_some(self) -> T {
+        self.extract(|option| option.ok_or_else(|| "Expected Some, but was None".to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OptionExpectations;
+    use crate::expect;
+    use crate::expectations::EqualityExpectations;
+
+    #[test]
+    pub fn that_to_be_none_or_matching_accepts_none_and_matching_values() {
+        // Given an empty Option and one with a matching value
+        let none: Option<u32> = None;
+        let some = Some(8);
+
+        // Expect to_be_none_or_matching to pass for both
+        expect(none).to_be_none_or_matching(|v| v % 2 == 0);
+        expect(some).to_be_none_or_matching(|v| v % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected None or Some matching the predicate, but was Some(7) which does not match"
+    )]
+    pub fn that_to_be_none_or_matching_does_not_accept_values_not_matching() {
+        // Given an Option with a value that doesn't match
+        let option = Some(7);
+
+        // Expect to_be_none_or_matching to fail
+        expect(option).to_be_none_or_matching(|v| v % 2 == 0);
+    }
+
+    #[test]
+    pub fn that_unwrap_some_returns_the_value() {
+        // Given an Option with a value
+        let option = Some("foo".to_string());
+
+        // When it's unwrapped
+        let value = expect(option).unwrap_some();
+
+        // Then the value is moved out
+        expect(value).to_equal("foo".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Some, but was None")]
+    pub fn that_unwrap_some_panics_on_none() {
+        // Given an empty Option
+        let option: Option<u32> = None;
+
+        // Expect unwrap_some to panic
+        expect(option).unwrap_some();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Some, but was None\nat src/expectations/option.rs:")]
+    pub fn that_unwrap_some_reports_the_location_of_expect() {
+        // Expect unwrap_some on None to point at the line calling expect
+        expect(None::<u32>).unwrap_some();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectation failed (expected == actual)")]
+    pub fn that_unwrap_some_checks_previous_expectations_first() {
+        // Given an Option with a value
+        let option = Some(1);
+
+        // Expect unwrap_some to panic when an earlier expectation fails
+        expect(option).to_equal(Some(2)).unwrap_some();
+    }
+}
@@ -0,0 +1,93 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::format;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on values with an ordering
+pub trait OrderExpectations<'e, T> {
+    /// Expect the value to be between `low` and `high`, including both bounds
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::OrderExpectations;
+    ///
+    /// expect(10).to_be_between(1, 10);
+    /// ```
+    fn to_be_between(self, low: T, high: T) -> Self;
+
+    /// Expect the value to be between `low` and `high`, excluding both bounds
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::OrderExpectations;
+    ///
+    /// expect("b").to_be_strictly_between("a", "c");
+    /// ```
+    fn to_be_strictly_between(self, low: T, high: T) -> Self;
+}
+
+impl<'e, T, B> OrderExpectations<'e, T> for B
+where
+    T: PartialOrd + Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_be_between(self, low: T, high: T) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_between",
+            (low, high),
+            |actual: &T, (low, high)| low <= actual && actual <= high,
+            |actual, (low, high)| {
+                format!(
+                    "Expected {:?} to be between {:?} and {:?} inclusive",
+                    actual, low, high
+                )
+            },
+        ))
+    }
+
+    fn to_be_strictly_between(self, low: T, high: T) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_strictly_between",
+            (low, high),
+            |actual: &T, (low, high)| low < actual && actual < high,
+            |actual, (low, high)| {
+                format!(
+                    "Expected {:?} to be between {:?} and {:?} exclusive",
+                    actual, low, high
+                )
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_be_between_accepts_the_bounds() {
+        // Expect values inside and at the bounds to pass
+        expect(1).to_be_between(1, 10);
+        expect(5).to_be_between(1, 10);
+        expect(10).to_be_between(1, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 11 to be between 1 and 10 inclusive")]
+    pub fn that_to_be_between_does_not_accept_values_outside_the_bounds() {
+        // Expect a value above the upper bound to fail
+        expect(11).to_be_between(1, 10);
+    }
+
+    #[test]
+    pub fn that_to_be_strictly_between_accepts_values_inside_the_bounds() {
+        // Expect a value inside the bounds to pass
+        expect(0.5).to_be_strictly_between(0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 1 to be between 1 and 10 exclusive")]
+    pub fn that_to_be_strictly_between_does_not_accept_the_bounds() {
+        // Expect the lower bound to fail
+        expect(1).to_be_strictly_between(1, 10);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use std::ffi::OsStr;
+use std::fmt::Debug;
+use std::path::Path;
+
+/// Extension trait for expectations on paths
+pub trait PathExpectations<'e, T> {
+    /// Expect the path to exist on the filesystem.
+    ///
+    /// This performs IO when the expectation is checked and requires the `fs` feature.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::PathExpectations;
+    ///
+    /// expect(std::env::temp_dir()).to_exist();
+    /// ```
+    #[cfg(feature = "fs")]
+    fn to_exist(self) -> Self;
+
+    /// Expect the path to point at a regular file, following symbolic links.
+    ///
+    /// This performs IO when the expectation is checked and requires the `fs` feature.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::PathExpectations;
+    ///
+    /// expect("Cargo.toml").to_be_a_file();
+    /// ```
+    #[cfg(feature = "fs")]
+    fn to_be_a_file(self) -> Self;
+
+    /// Expect the path to point at a directory, following symbolic links.
+    ///
+    /// This performs IO when the expectation is checked and requires the `fs` feature.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::PathExpectations;
+    ///
+    /// expect("src").to_be_a_dir();
+    /// ```
+    #[cfg(feature = "fs")]
+    fn to_be_a_dir(self) -> Self;
+
+    /// Expect the path to have the extension `ext`, given without the leading dot
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::PathExpectations;
+    ///
+    /// expect("archive.tar.gz").to_have_extension("gz");
+    /// ```
+    fn to_have_extension(self, ext: &'e str) -> Self;
+
+    /// Expect the final component of the path to be `name`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::PathExpectations;
+    ///
+    /// expect("/etc/hosts").to_have_file_name("hosts");
+    /// ```
+    fn to_have_file_name(self, name: &'e str) -> Self;
+}
+
+impl<'e, T, B> PathExpectations<'e, T> for B
+where
+    T: AsRef<Path> + Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    #[cfg(feature = "fs")]
+    fn to_exist(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_exist",
+            (),
+            |actual: &T, _| actual.as_ref().exists(),
+            |actual, _| format!("Expected {} to exist", actual.as_ref().display()),
+        ))
+    }
+
+    #[cfg(feature = "fs")]
+    fn to_be_a_file(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_a_file",
+            (),
+            |actual: &T, _| actual.as_ref().is_file(),
+            |actual, _| format!("Expected {} to be a file", actual.as_ref().display()),
+        ))
+    }
+
+    #[cfg(feature = "fs")]
+    fn to_be_a_dir(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_a_dir",
+            (),
+            |actual: &T, _| actual.as_ref().is_dir(),
+            |actual, _| format!("Expected {} to be a directory", actual.as_ref().display()),
+        ))
+    }
+
+    fn to_have_extension(self, ext: &'e str) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_extension",
+            ext,
+            |actual: &T, ext| actual.as_ref().extension() == Some(OsStr::new(ext)),
+            |actual, ext| {
+                format!(
+                    "Expected {} to have extension {:?}, but it had {:?}",
+                    actual.as_ref().display(),
+                    ext,
+                    actual.as_ref().extension()
+                )
+            },
+        ))
+    }
+
+    fn to_have_file_name(self, name: &'e str) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_file_name",
+            name,
+            |actual: &T, name| actual.as_ref().file_name() == Some(OsStr::new(name)),
+            |actual, name| {
+                format!(
+                    "Expected {} to have file name {:?}, but it had {:?}",
+                    actual.as_ref().display(),
+                    name,
+                    actual.as_ref().file_name()
+                )
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathExpectations;
+    use crate::expect;
+    use std::path::PathBuf;
+
+    #[test]
+    pub fn that_to_have_extension_accepts_matching_extensions() {
+        // Given a path with an extension
+        let path = PathBuf::from("src/lib.rs");
+
+        // Expect to_have_extension to pass
+        expect(path).to_have_extension("rs");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected README to have extension \"md\", but it had None")]
+    pub fn that_to_have_extension_does_not_accept_missing_extensions() {
+        // Given a path without an extension
+        let path = "README";
+
+        // Expect to_have_extension to fail
+        expect(path).to_have_extension("md");
+    }
+
+    #[test]
+    pub fn that_to_have_file_name_accepts_matching_names() {
+        // Given a path to a file in a directory
+        let path = PathBuf::from("/tmp/data.json");
+
+        // Expect to_have_file_name to pass
+        expect(path).to_have_file_name("data.json");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected /tmp/data.json to have file name \"other.json\", but it had Some(\"data.json\")"
+    )]
+    pub fn that_to_have_file_name_does_not_accept_other_names() {
+        // Given a path to a file in a directory
+        let path = "/tmp/data.json";
+
+        // Expect to_have_file_name to fail for another name
+        expect(path).to_have_file_name("other.json");
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    pub fn that_fs_expectations_accept_existing_paths() {
+        // Expect files and directories in the crate to be found
+        expect(env!("CARGO_MANIFEST_DIR")).to_exist().to_be_a_dir();
+        expect(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+            .to_exist()
+            .to_be_a_file();
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    #[should_panic(expected = "to exist")]
+    pub fn that_to_exist_does_not_accept_missing_paths() {
+        // Given a path that doesn't exist
+        let path = std::env::temp_dir().join("rxpect-does-not-exist");
+
+        // Expect to_exist to fail
+        expect(path).to_exist();
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    #[should_panic(expected = "to be a file")]
+    pub fn that_to_be_a_file_does_not_accept_directories() {
+        // Expect a directory to not be a file
+        expect(env!("CARGO_MANIFEST_DIR")).to_be_a_file();
+    }
+}
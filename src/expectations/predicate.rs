@@ -0,0 +1,154 @@
+use crate::{CheckResult, Expectation, Failure};
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+/// Generic expectation that checks a value against a reference value using a predicate.
+///
+/// The message function is only called when the predicate fails.
+/// Failures record the name of the matcher, the actual value and the expected value.
+pub(crate) struct PredicateExpectation<T, R, P, M>
+where
+    P: Fn(&T, &R) -> bool,
+    M: Fn(&T, &R) -> String,
+{
+    matcher: &'static str,
+    reference: R,
+    expected: fn(&R) -> Option<String>,
+    predicate: P,
+    message: M,
+    _t: PhantomData<fn(&T)>,
+}
+
+impl<T, R, P, M> PredicateExpectation<T, R, P, M>
+where
+    P: Fn(&T, &R) -> bool,
+    M: Fn(&T, &R) -> String,
+{
+    /// Create an expectation that records the reference as the expected value.
+    /// Zero-sized references, such as `()` for matchers without one, aren't recorded
+    pub(crate) fn new(matcher: &'static str, reference: R, predicate: P, message: M) -> Self
+    where
+        R: Debug,
+    {
+        Self::with_expected(
+            matcher,
+            reference,
+            |reference| match size_of::<R>() {
+                0 => None,
+                _ => Some(format!("{:?}", reference)),
+            },
+            predicate,
+            message,
+        )
+    }
+
+    /// Create an expectation that records the expected value extracted from the reference by
+    /// `expected`, for references that can't be formatted as a whole, such as closures
+    pub(crate) fn with_expected(
+        matcher: &'static str,
+        reference: R,
+        expected: fn(&R) -> Option<String>,
+        predicate: P,
+        message: M,
+    ) -> Self {
+        PredicateExpectation {
+            matcher,
+            reference,
+            expected,
+            predicate,
+            message,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T, R, P, M> Expectation<T> for PredicateExpectation<T, R, P, M>
+where
+    T: Debug,
+    P: Fn(&T, &R) -> bool,
+    M: Fn(&T, &R) -> String,
+{
+    fn check(&self, value: &T) -> CheckResult {
+        if (self.predicate)(value, &self.reference) {
+            CheckResult::Pass
+        } else {
+            let failure = Failure::new((self.message)(value, &self.reference))
+                .with_actual(format!("{:?}", value))
+                .with_matcher(self.matcher);
+            CheckResult::FailWith(match (self.expected)(&self.reference) {
+                Some(expected) => failure.with_expected(expected),
+                None => failure,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PredicateExpectation;
+    use crate::{CheckResult, Expectation};
+
+    #[test]
+    pub fn that_predicate_expectation_passes_when_predicate_holds() {
+        // Given an expectation with a predicate that holds
+        let expectation = PredicateExpectation::new(
+            "to_be_one",
+            1,
+            |actual: &i32, reference: &i32| actual == reference,
+            |_, _| "message".to_owned(),
+        );
+
+        // Expect the check to pass
+        assert!(matches!(expectation.check(&1), CheckResult::Pass));
+    }
+
+    #[test]
+    pub fn that_predicate_expectation_uses_message_on_failure() {
+        // Given an expectation with a predicate that does not hold
+        let expectation = PredicateExpectation::new(
+            "to_be_one",
+            1,
+            |actual: &i32, reference: &i32| actual == reference,
+            |actual, reference| format!("{actual} != {reference}"),
+        );
+
+        // When the expectation is checked
+        let result = expectation.check(&2);
+
+        // Then the failure message is built by the message function
+        // and the matcher, the actual and the expected value are recorded
+        if let CheckResult::FailWith(failure) = result {
+            assert_eq!("2 != 1", failure.message());
+            assert_eq!(Some("to_be_one"), failure.matcher());
+            assert_eq!(Some("2"), failure.actual());
+            assert_eq!(Some("1"), failure.expected());
+        } else {
+            panic!("Result was a pass when failure was expected");
+        }
+    }
+
+    #[test]
+    pub fn that_predicate_expectation_does_not_record_zero_sized_references() {
+        // Given an expectation without a reference value
+        let expectation = PredicateExpectation::new(
+            "to_be_odd",
+            (),
+            |actual: &i32, _| actual % 2 == 1,
+            |_, _| "message".to_owned(),
+        );
+
+        // When the expectation is checked
+        let result = expectation.check(&2);
+
+        // Then no expected value is recorded
+        if let CheckResult::FailWith(failure) = result {
+            assert_eq!(None, failure.expected());
+            assert_eq!(Some("to_be_odd"), failure.matcher());
+        } else {
+            panic!("Result was a pass when failure was expected");
+        }
+    }
+}
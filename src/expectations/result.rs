@@ -0,0 +1,638 @@
+use crate::expectations::predicate::PredicateExpectation;
+#[cfg(feature = "float")]
+use crate::expectations::Float;
+use crate::{expect, ExpectationBuilder, RootExpectations};
+#[cfg(any(feature = "float", feature = "snapshot"))]
+use crate::{CheckResult, Expectation, Failure};
+use alloc::boxed::Box;
+use alloc::format;
+use core::any::{type_name, Any};
+use core::error::Error;
+use core::fmt::Debug;
+use core::ops::RangeBounds;
+#[cfg(feature = "snapshot")]
+use std::path::{Path, PathBuf};
+
+/// Extension trait for expectations on [Result] values
+pub trait ResultExpectations<'e, T, E> {
+    /// Expect the value to be `Ok` with a value within `tolerance` of `expected`.
+    ///
+    /// Requires the `float` feature.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::ResultExpectations;
+    ///
+    /// let result: Result<f64, String> = Ok(0.1 + 0.2);
+    /// expect(result).to_be_ok_close_to(0.3, 1e-9);
+    /// ```
+    #[cfg(feature = "float")]
+    fn to_be_ok_close_to(self, expected: T, tolerance: T) -> Self
+    where
+        T: Float;
+
+    /// Expect the value to be `Err` with an error that `classify` maps to `expected`.
+    ///
+    /// This allows asserting on the kind of error without requiring `PartialEq` on the error type.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::ResultExpectations;
+    /// use std::num::{IntErrorKind, ParseIntError};
+    ///
+    /// let result: Result<u8, ParseIntError> = "300".parse();
+    /// expect(result).to_be_err_classified_as(|e| e.kind().clone(), IntErrorKind::PosOverflow);
+    /// ```
+    fn to_be_err_classified_as<K, F>(self, classify: F, expected: K) -> Self
+    where
+        K: PartialEq + Debug + 'e,
+        F: Fn(&E) -> K + 'e;
+
+    /// Expect the value to be `Err` with an error of the concrete type `X`.
+    ///
+    /// The error is downcast through [Error](std::error::Error), which only works for `'static`
+    /// types, so both `X` and the error must be `'static`.
+    /// This works for concrete error types as well as for `Box<dyn Error>`,
+    /// `Box<dyn Error + Send>` and `Box<dyn Error + Send + Sync>`.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::ResultExpectations;
+    /// use std::error::Error;
+    /// use std::num::ParseIntError;
+    ///
+    /// let result: Result<u8, Box<dyn Error>> = "foo".parse::<u8>().map_err(Into::into);
+    /// expect(result).to_be_err_of_type::<ParseIntError>();
+    /// ```
+    fn to_be_err_of_type<X>(self) -> Self
+    where
+        X: Error + 'static,
+        E: 'static;
+
+    /// Expect the value to be `Ok` with a value that matches the snapshot file at `path`.
+    ///
+    /// The snapshot is never read or written if the value is an `Err`.
+    /// See [to_match_snapshot](crate::expectations::SnapshotExpectations::to_match_snapshot)
+    /// for how snapshots are compared and updated.
+    ///
+    /// Requires the `snapshot` feature.
+    /// ```no_run
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::ResultExpectations;
+    ///
+    /// let result: Result<Vec<u32>, String> = Ok(vec![1, 2, 3]);
+    /// expect(result).to_be_ok_matching_snapshot("tests/snapshots/numbers.snap");
+    /// ```
+    #[cfg(feature = "snapshot")]
+    fn to_be_ok_matching_snapshot(self, path: impl AsRef<Path>) -> Self;
+}
+
+impl<'e, T, E, B> ResultExpectations<'e, T, E> for B
+where
+    T: Debug + 'e,
+    E: Debug + 'e,
+    B: ExpectationBuilder<'e, Result<T, E>>,
+{
+    #[cfg(feature = "float")]
+    fn to_be_ok_close_to(self, expected: T, tolerance: T) -> Self
+    where
+        T: Float,
+    {
+        self.to_pass(OkCloseToExpectation {
+            expected,
+            tolerance,
+        })
+    }
+
+    fn to_be_err_classified_as<K, F>(self, classify: F, expected: K) -> Self
+    where
+        K: PartialEq + Debug + 'e,
+        F: Fn(&E) -> K + 'e,
+    {
+        self.to_pass(PredicateExpectation::with_expected(
+            "to_be_err_classified_as",
+            (classify, expected),
+            |(_, expected)| Some(format!("{:?}", expected)),
+            |actual: &Result<T, E>, (classify, expected)| match actual {
+                Err(error) => classify(error) == *expected,
+                Ok(_) => false,
+            },
+            |actual, (classify, expected)| match actual {
+                Err(error) => format!(
+                    "Expected Err classified as {:?}, but it was classified as {:?}\nactual: {:?}",
+                    expected,
+                    classify(error),
+                    actual
+                ),
+                Ok(value) => format!(
+                    "Expected Err classified as {:?}, but was Ok({:?})",
+                    expected, value
+                ),
+            },
+        ))
+    }
+
+    fn to_be_err_of_type<X>(self) -> Self
+    where
+        X: Error + 'static,
+        E: 'static,
+    {
+        self.to_pass(PredicateExpectation::new(
+            "to_be_err_of_type",
+            (),
+            |actual: &Result<T, E>, _| match actual {
+                Err(error) => is_error_of_type::<X>(error),
+                Ok(_) => false,
+            },
+            |actual, _| match actual {
+                Err(error) => format!(
+                    "Expected Err of type {}, but it was {:?}",
+                    type_name::<X>(),
+                    error
+                ),
+                Ok(value) => format!(
+                    "Expected Err of type {}, but was Ok({:?})",
+                    type_name::<X>(),
+                    value
+                ),
+            },
+        ))
+    }
+
+    #[cfg(feature = "snapshot")]
+    fn to_be_ok_matching_snapshot(self, path: impl AsRef<Path>) -> Self {
+        self.to_pass(OkMatchingSnapshotExpectation(path.as_ref().to_path_buf()))
+    }
+}
+
+impl<'e, T, E> RootExpectations<'e, Result<T, E>>
+where
+    T: Debug + 'e,
+    E: Debug,
+{
+    /// Expect the value to be `Ok` and continue with expectations on the value inside it.
+    ///
+    /// Any expectations already added are checked first, and this panics immediately if any of
+    /// them fail or if the value is an `Err`.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::EqualityExpectations;
+    ///
+    /// let result: Result<u32, String> = Ok(7);
+    /// expect(result).assert_ok().to_equal(7);
+    /// ```
+    #[track_caller]
+    pub fn assert_ok(self) -> RootExpectations<'e, T> {
+        expect(self.unwrap_ok())
+    }
+
+    /// Expect the value to be `Ok` and return the value inside it.
+    ///
+    /// Any expectations already added are checked first, and this panics immediately if any of
+    /// them fail or if the value is an `Err`.
+    /// The value is moved out of the expectations, so nothing is checked again when they are dropped.
+    /// ```
+    /// # use rxpect::expect;
+    ///
+    /// let result: Result<u32, String> = Ok(7);
+    /// let value = expect(result).unwrap_ok();
+    /// assert_eq!(value, 7);
+    /// ```
+    #[track_caller]
+    pub fn unwrap_ok(self) -> T {
+        self.extract(|result| match result {
+            Ok(value) => Ok(value),
+            Err(error) => Err(format!("Expected Ok, but was Err({:?})", error)),
+        })
+    }
+
+    /// Expect the value to be `Err` and return the error inside it.
+    ///
+    /// Any expectations already added are checked first, and this panics immediately if any of
+    /// them fail or if the value is an `Ok`.
+    /// The value is moved out of the expectations, so nothing is checked again when they are dropped.
+    /// ```
+    /// # use rxpect::expect;
+    ///
+    /// let result: Result<u32, String> = Err("failure".to_string());
+    /// let error = expect(result).unwrap_err();
+    /// assert_eq!(error, "failure");
+    /// ```
+    #[track_caller]
+    pub fn unwrap_err(self) -> E {
+        self.extract(|result| match result {
+            Ok(value) => Err(format!("Expected Err, but was Ok({:?})", value)),
+            Err(error) => Ok(error),
+        })
+    }
+
+    /// Expect the value to be `Ok` with a value inside `range` and return the value inside it.
+    ///
+    /// Any expectations already added are checked first, and this panics immediately if any of
+    /// them fail, if the value is an `Err` or if the value inside it is outside of the range.
+    /// ```
+    /// # use rxpect::expect;
+    ///
+    /// let result: Result<u32, String> = "42".parse::<u32>().map_err(|e| e.to_string());
+    /// let port = expect(result).extract_ok_in_range(1..=65535);
+    /// assert_eq!(port, 42);
+    /// ```
+    #[track_caller]
+    pub fn extract_ok_in_range(self, range: impl RangeBounds<T> + Debug) -> T
+    where
+        T: PartialOrd,
+    {
+        self.extract(|result| match result {
+            Ok(value) if range.contains(&value) => Ok(value),
+            Ok(value) => Err(format!(
+                "Expected Ok inside {:?}, but was Ok({:?}) which is out of range",
+                range, value
+            )),
+            Err(error) => Err(format!(
+                "Expected Ok inside {:?}, but was Err({:?})",
+                range, error
+            )),
+        })
+    }
+}
+
+/// Check if `error` is an `X`, either directly or boxed as one of the common error trait objects
+fn is_error_of_type<X: Error + 'static>(error: &dyn Any) -> bool {
+    if let Some(error) = error.downcast_ref::<Box<dyn Error>>() {
+        error.is::<X>()
+    } else if let Some(error) = error.downcast_ref::<Box<dyn Error + Send>>() {
+        error.is::<X>()
+    } else if let Some(error) = error.downcast_ref::<Box<dyn Error + Send + Sync>>() {
+        error.is::<X>()
+    } else {
+        error.is::<X>()
+    }
+}
+
+/// Expectation for to_be_ok_close_to
+#[cfg(feature = "float")]
+struct OkCloseToExpectation<T> {
+    expected: T,
+    tolerance: T,
+}
+
+#[cfg(feature = "float")]
+impl<T: Float, E: Debug> Expectation<Result<T, E>> for OkCloseToExpectation<T> {
+    fn check(&self, value: &Result<T, E>) -> CheckResult {
+        let message = match value {
+            Err(error) => format!(
+                "Expected Ok close to {}, but was Err({:?})",
+                self.expected, error
+            ),
+            Ok(actual) => {
+                let difference = (*actual - self.expected).abs();
+                if difference <= self.tolerance {
+                    return CheckResult::Pass;
+                }
+                format!(
+                    "Expected Ok({}) to be within {} of {}, but the difference was {}",
+                    actual, self.tolerance, self.expected, difference
+                )
+            }
+        };
+        CheckResult::FailWith(
+            Failure::new(message)
+                .with_expected(format!("Ok({:?})", self.expected))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_be_ok_close_to"),
+        )
+    }
+}
+
+/// Expectation for to_be_ok_matching_snapshot
+#[cfg(feature = "snapshot")]
+struct OkMatchingSnapshotExpectation(PathBuf);
+
+#[cfg(feature = "snapshot")]
+impl<T: Debug, E: Debug> Expectation<Result<T, E>> for OkMatchingSnapshotExpectation {
+    fn check(&self, value: &Result<T, E>) -> CheckResult {
+        match value {
+            Ok(actual) => crate::expectations::snapshot::check_snapshot(
+                actual,
+                &self.0,
+                "to_be_ok_matching_snapshot",
+            ),
+            Err(error) => CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected Ok matching snapshot {}, but was Err({:?})",
+                    self.0.display(),
+                    error
+                ))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_be_ok_matching_snapshot"),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResultExpectations;
+    use crate::expect;
+    use crate::expectations::EqualityExpectations;
+
+    #[test]
+    #[cfg(feature = "float")]
+    pub fn that_to_be_ok_close_to_accepts_close_values() {
+        // Given an Ok result with rounding errors
+        let result: Result<f64, ()> = Ok(0.1 + 0.2);
+
+        // Expect to_be_ok_close_to to pass
+        expect(result).to_be_ok_close_to(0.3, 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    #[should_panic(expected = "but the difference was")]
+    pub fn that_to_be_ok_close_to_does_not_accept_values_out_of_tolerance() {
+        // Given an Ok result
+        let result: Result<f32, ()> = Ok(1.0);
+
+        // Expect to_be_ok_close_to to fail when the value is too far off
+        expect(result).to_be_ok_close_to(1.5, 0.1);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    #[should_panic(expected = "but was Err(\"failure\")")]
+    pub fn that_to_be_ok_close_to_does_not_accept_errors() {
+        // Given an Err result
+        let result: Result<f64, &str> = Err("failure");
+
+        // Expect to_be_ok_close_to to fail
+        expect(result).to_be_ok_close_to(1.0, 0.1);
+    }
+
+    #[derive(Debug)]
+    enum TestError {
+        NotFound,
+        Denied,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum TestErrorKind {
+        NotFound,
+        Denied,
+    }
+
+    fn classify(error: &TestError) -> TestErrorKind {
+        match error {
+            TestError::NotFound => TestErrorKind::NotFound,
+            TestError::Denied => TestErrorKind::Denied,
+        }
+    }
+
+    #[test]
+    pub fn that_to_be_err_classified_as_accepts_errors_of_the_kind() {
+        // Given an error without PartialEq
+        let result: Result<(), TestError> = Err(TestError::NotFound);
+
+        // Expect it to be classified as NotFound
+        expect(result).to_be_err_classified_as(classify, TestErrorKind::NotFound);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected Err classified as NotFound, but it was classified as Denied"
+    )]
+    pub fn that_to_be_err_classified_as_does_not_accept_errors_of_other_kinds() {
+        // Given an error of another kind
+        let result: Result<(), TestError> = Err(TestError::Denied);
+
+        // Expect to_be_err_classified_as to fail
+        expect(result).to_be_err_classified_as(classify, TestErrorKind::NotFound);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Err classified as Denied, but was Ok(7)")]
+    pub fn that_to_be_err_classified_as_does_not_accept_ok() {
+        // Given an Ok result
+        let result: Result<u32, TestError> = Ok(7);
+
+        // Expect to_be_err_classified_as to fail
+        expect(result).to_be_err_classified_as(classify, TestErrorKind::Denied);
+    }
+
+    fn parse(value: &str) -> Result<u8, Box<dyn std::error::Error>> {
+        Ok(value.parse()?)
+    }
+
+    #[test]
+    pub fn that_to_be_err_of_type_accepts_errors_of_the_type() {
+        // Given a boxed ParseIntError
+        let result = parse("foo");
+
+        // Expect it to be a ParseIntError
+        expect(result).to_be_err_of_type::<std::num::ParseIntError>();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected Err of type core::num::error::TryFromIntError, but it was ParseIntError { kind: InvalidDigit }"
+    )]
+    pub fn that_to_be_err_of_type_does_not_accept_errors_of_other_types() {
+        // Given a boxed ParseIntError
+        let result = parse("foo");
+
+        // Expect to_be_err_of_type to fail for another error type
+        expect(result).to_be_err_of_type::<std::num::TryFromIntError>();
+    }
+
+    #[test]
+    pub fn that_to_be_err_of_type_accepts_thread_safe_boxed_errors() {
+        // Given an error boxed as Send + Sync
+        let result: Result<u8, Box<dyn std::error::Error + Send + Sync>> =
+            "foo".parse::<u8>().map_err(Into::into);
+
+        // Expect it to be a ParseIntError
+        expect(result).to_be_err_of_type::<std::num::ParseIntError>();
+    }
+
+    #[test]
+    pub fn that_to_be_err_of_type_accepts_concrete_errors() {
+        // Given a concrete error
+        let result: Result<u8, std::io::Error> = Err(std::io::Error::other("failure"));
+
+        // Expect it to be an io::Error
+        expect(result).to_be_err_of_type::<std::io::Error>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Err of type core::num::error::ParseIntError, but it was")]
+    pub fn that_to_be_err_of_type_does_not_accept_concrete_errors_of_other_types() {
+        // Given a concrete error
+        let result: Result<u8, std::io::Error> = Err(std::io::Error::other("failure"));
+
+        // Expect to_be_err_of_type to fail for another error type
+        expect(result).to_be_err_of_type::<std::num::ParseIntError>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Err of type std::io::error::Error, but was Ok(7)")]
+    pub fn that_to_be_err_of_type_does_not_accept_ok() {
+        // Given an Ok result
+        let result = parse("7");
+
+        // Expect to_be_err_of_type to fail
+        expect(result).to_be_err_of_type::<std::io::Error>();
+    }
+
+    #[test]
+    pub fn that_assert_ok_continues_with_the_ok_value() {
+        // Given an Ok result
+        let result: Result<&str, ()> = Ok("foo");
+
+        // Expect further expectations to be on the Ok value
+        expect(result).assert_ok().to_equal("foo");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Ok, but was Err(\"failure\")")]
+    pub fn that_assert_ok_panics_on_errors() {
+        // Given an Err result
+        let result: Result<u32, &str> = Err("failure");
+
+        // Expect assert_ok to panic
+        expect(result).assert_ok();
+    }
+
+    #[test]
+    #[cfg(feature = "snapshot")]
+    pub fn that_to_be_ok_matching_snapshot_accepts_matching_ok_values() {
+        // Given a snapshot of a value
+        let path = crate::expectations::snapshot::tests::snapshot_path("ok_matching");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "\"foo\"").unwrap();
+
+        // Expect an Ok with the same value to match it
+        let result: Result<&str, ()> = Ok("foo");
+        expect(result).to_be_ok_matching_snapshot(&path);
+    }
+
+    #[test]
+    #[cfg(feature = "snapshot")]
+    #[should_panic(expected = "Expected value to match snapshot")]
+    pub fn that_to_be_ok_matching_snapshot_does_not_accept_differing_ok_values() {
+        // Given a snapshot of a value
+        let path = crate::expectations::snapshot::tests::snapshot_path("ok_differing");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "\"foo\"").unwrap();
+
+        // Expect an Ok with another value to not match it
+        let result: Result<&str, ()> = Ok("bar");
+        expect(result).to_be_ok_matching_snapshot(&path);
+    }
+
+    #[test]
+    #[cfg(feature = "snapshot")]
+    #[should_panic(expected = "but was Err(\"failure\")")]
+    pub fn that_to_be_ok_matching_snapshot_does_not_accept_errors() {
+        // Given a path without a snapshot
+        let path = crate::expectations::snapshot::tests::snapshot_path("ok_error");
+
+        // Expect an Err to fail without reading the snapshot
+        let result: Result<&str, &str> = Err("failure");
+        expect(result).to_be_ok_matching_snapshot(&path);
+    }
+
+    #[test]
+    pub fn that_unwrap_ok_returns_the_ok_value() {
+        // Given an Ok result
+        let result: Result<String, ()> = Ok("foo".to_string());
+
+        // When it's unwrapped
+        let value = expect(result).unwrap_ok();
+
+        // Then the value is moved out
+        expect(value).to_equal("foo".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Ok, but was Err(\"failure\")")]
+    pub fn that_unwrap_ok_panics_on_errors() {
+        // Given an Err result
+        let result: Result<u32, &str> = Err("failure");
+
+        // Expect unwrap_ok to panic
+        expect(result).unwrap_ok();
+    }
+
+    #[test]
+    pub fn that_unwrap_ok_reports_errors_like_failed_expectations() {
+        // Given an Err result
+        let result: Result<u32, &str> = Err("failure");
+
+        // When it's unwrapped with a description
+        let line = line!() + 2;
+        let panic =
+            std::panic::catch_unwind(|| expect(result).described_as("the answer").unwrap_ok());
+
+        // Then the failure is described and points at the line calling expect
+        let panic = panic.expect_err("unwrap_ok to fail");
+        let message = panic.downcast_ref::<String>().expect("a formatted message");
+        assert_eq!(
+            &format!(
+                "the answer\n  Expected Ok, but was Err(\"failure\")\nat {}:{}",
+                file!(),
+                line
+            ),
+            message
+        );
+    }
+
+    #[test]
+    pub fn that_unwrap_err_returns_the_error() {
+        // Given an Err result
+        let result: Result<u32, &str> = Err("failure");
+
+        // When it's unwrapped
+        let error = expect(result).unwrap_err();
+
+        // Then the error is moved out
+        expect(error).to_equal("failure");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Err, but was Ok(7)")]
+    pub fn that_unwrap_err_panics_on_ok() {
+        // Given an Ok result
+        let result: Result<u32, &str> = Ok(7);
+
+        // Expect unwrap_err to panic
+        expect(result).unwrap_err();
+    }
+
+    #[test]
+    pub fn that_extract_ok_in_range_returns_the_ok_value() {
+        // Given an Ok result
+        let result: Result<u32, ()> = Ok(7);
+
+        // When extracting it within a range that contains it
+        let value = expect(result).extract_ok_in_range(1..10);
+
+        // Then the value is returned
+        expect(value).to_equal(7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Ok inside 1..10, but was Ok(10) which is out of range")]
+    pub fn that_extract_ok_in_range_panics_on_values_out_of_range() {
+        // Given an Ok result outside of the range
+        let result: Result<u32, ()> = Ok(10);
+
+        // Expect extract_ok_in_range to panic
+        expect(result).extract_ok_in_range(1..10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Ok inside 1..10, but was Err(\"failure\")")]
+    pub fn that_extract_ok_in_range_panics_on_errors() {
+        // Given an Err result
+        let result: Result<u32, &str> = Err("failure");
+
+        // Expect extract_ok_in_range to panic
+        expect(result).extract_ok_in_range(1..10);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// Extension trait for round trip expectations
+pub trait RoundTripExpectations<'e, T> {
+    /// Expect the value to survive a round trip through a pair of transformations,
+    /// i.e. that `backward(&forward(&value)) == value`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::RoundTripExpectations;
+    ///
+    /// expect(42).round_trips_through(|v| v.to_string(), |s| s.parse().unwrap());
+    /// ```
+    fn round_trips_through<U, F, G>(self, forward: F, backward: G) -> Self
+    where
+        U: Debug + 'e,
+        F: Fn(&T) -> U + 'e,
+        G: Fn(&U) -> T + 'e;
+}
+
+impl<'e, T, B> RoundTripExpectations<'e, T> for B
+where
+    T: PartialEq + Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn round_trips_through<U, F, G>(self, forward: F, backward: G) -> Self
+    where
+        U: Debug + 'e,
+        F: Fn(&T) -> U + 'e,
+        G: Fn(&U) -> T + 'e,
+    {
+        self.to_pass(RoundTripExpectation {
+            forward,
+            backward,
+            _u: PhantomData,
+        })
+    }
+}
+
+/// Expectation for round_trips_through
+struct RoundTripExpectation<U, F, G> {
+    forward: F,
+    backward: G,
+    _u: PhantomData<fn(&U)>,
+}
+
+impl<T, U, F, G> Expectation<T> for RoundTripExpectation<U, F, G>
+where
+    T: PartialEq + Debug,
+    U: Debug,
+    F: Fn(&T) -> U,
+    G: Fn(&U) -> T,
+{
+    fn check(&self, value: &T) -> CheckResult {
+        let intermediate = (self.forward)(value);
+        let result = (self.backward)(&intermediate);
+        if result.eq(value) {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expectation failed (original == backward(forward(original)))\n    original: `{:?}`\nintermediate: `{:?}`\n       final: `{:?}`",
+                    value, intermediate, result
+                ))
+                .with_expected(format!("{:?}", value))
+                .with_actual(format!("{:?}", result))
+                .with_matcher("round_trips_through"),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoundTripExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_round_trips_through_accepts_inverse_transformations() {
+        // Given a value
+        let value = 42;
+
+        // Expect it to round trip through formatting and parsing
+        expect(value).round_trips_through(|v| v.to_string(), |s| s.parse().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "intermediate: `\"42\"`")]
+    pub fn that_round_trips_through_does_not_accept_lossy_transformations() {
+        // Given a value
+        let value = 42;
+
+        // Expect a lossy round trip to fail
+        expect(value).round_trips_through(|v| v.to_string(), |s| s.len() as i32);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// A boxed predicate on a value, allowing lists of different closures
+pub type Predicate<'e, T> = Box<dyn Fn(&T) -> bool + 'e>;
+
+/// Extension trait for expectations using predicates on any value
+pub trait SatisfyExpectations<'e, T> {
+    /// Expect the value to match at least `count` of the predicates
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{Predicate, SatisfyExpectations};
+    ///
+    /// let rules: Vec<Predicate<&str>> = vec![
+    ///     Box::new(|password| password.len() >= 8),
+    ///     Box::new(|password| password.chars().any(|c| c.is_ascii_digit())),
+    ///     Box::new(|password| password.chars().any(|c| c.is_uppercase())),
+    /// ];
+    /// expect("hunter22").to_match_at_least(2, rules);
+    /// ```
+    fn to_match_at_least(self, count: usize, predicates: Vec<Predicate<'e, T>>) -> Self;
+
+    /// Expect the value to satisfy a predicate
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::SatisfyExpectations;
+    ///
+    /// expect(42).to_satisfy(|n| n % 7 == 0);
+    /// ```
+    fn to_satisfy(self, predicate: impl Fn(&T) -> bool + 'e) -> Self;
+
+    /// Expect the value to satisfy a predicate, using `description` in the failure message
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::SatisfyExpectations;
+    ///
+    /// fn is_prime(n: &u32) -> bool {
+    ///     *n > 1 && (2..*n).all(|d| n % d != 0)
+    /// }
+    ///
+    /// expect(13).to_satisfy_described("is prime", is_prime);
+    /// ```
+    fn to_satisfy_described(
+        self,
+        description: &'e str,
+        predicate: impl Fn(&T) -> bool + 'e,
+    ) -> Self;
+}
+
+impl<'e, T, B> SatisfyExpectations<'e, T> for B
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_match_at_least(self, count: usize, predicates: Vec<Predicate<'e, T>>) -> Self {
+        self.to_pass(MatchAtLeastExpectation { count, predicates })
+    }
+
+    fn to_satisfy(self, predicate: impl Fn(&T) -> bool + 'e) -> Self {
+        self.to_pass(SatisfyExpectation {
+            description: None,
+            predicate,
+        })
+    }
+
+    fn to_satisfy_described(
+        self,
+        description: &'e str,
+        predicate: impl Fn(&T) -> bool + 'e,
+    ) -> Self {
+        self.to_pass(SatisfyExpectation {
+            description: Some(description),
+            predicate,
+        })
+    }
+}
+
+/// Expectation for to_satisfy and to_satisfy_described
+struct SatisfyExpectation<'e, F> {
+    description: Option<&'e str>,
+    predicate: F,
+}
+
+impl<T: Debug, F: Fn(&T) -> bool> Expectation<T> for SatisfyExpectation<'_, F> {
+    fn check(&self, value: &T) -> CheckResult {
+        if (self.predicate)(value) {
+            CheckResult::Pass
+        } else {
+            let failure = match self.description {
+                Some(description) => Failure::new(format!(
+                    "Expected {:?} to satisfy \"{}\"",
+                    value, description
+                ))
+                .with_expected(description)
+                .with_matcher("to_satisfy_described"),
+                None => Failure::new(format!("Expected {:?} to satisfy the predicate", value))
+                    .with_matcher("to_satisfy"),
+            };
+            CheckResult::FailWith(failure.with_actual(format!("{:?}", value)))
+        }
+    }
+}
+
+/// Expectation for to_match_at_least
+struct MatchAtLeastExpectation<'e, T> {
+    count: usize,
+    predicates: Vec<Predicate<'e, T>>,
+}
+
+impl<T: Debug> Expectation<T> for MatchAtLeastExpectation<'_, T> {
+    fn check(&self, value: &T) -> CheckResult {
+        let failed = self
+            .predicates
+            .iter()
+            .enumerate()
+            .filter(|(_, predicate)| !predicate(value))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let matched = self.predicates.len() - failed.len();
+        if matched >= self.count {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected {:?} to match at least {} of {} predicates, but {} matched\nfailed predicates at index: {:?}",
+                    value,
+                    self.count,
+                    self.predicates.len(),
+                    matched,
+                    failed
+                ))
+                .with_expected(format!("at least {} of {} predicates", self.count, self.predicates.len()))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("to_match_at_least"),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Predicate, SatisfyExpectations};
+    use crate::expect;
+
+    fn rules<'e>() -> Vec<Predicate<'e, i32>> {
+        vec![
+            Box::new(|v| *v > 0),
+            Box::new(|v| v % 2 == 0),
+            Box::new(|v| *v < 100),
+        ]
+    }
+
+    #[test]
+    pub fn that_to_match_at_least_accepts_enough_matches() {
+        // Given a value that matches two of three predicates
+        let value = 102;
+
+        // Expect to_match_at_least to pass when two matches are required
+        expect(value).to_match_at_least(2, rules());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected -3 to match at least 2 of 3 predicates, but 1 matched\nfailed predicates at index: [0, 1]"
+    )]
+    pub fn that_to_match_at_least_does_not_accept_too_few_matches() {
+        // Given a value that matches one of three predicates
+        let value = -3;
+
+        // Expect to_match_at_least to fail when two matches are required
+        expect(value).to_match_at_least(2, rules());
+    }
+
+    #[test]
+    pub fn that_to_satisfy_accepts_matching_values() {
+        // Given a value divisible by three
+        let value = 9;
+
+        // Expect to_satisfy to pass
+        expect(value).to_satisfy(|v| v % 3 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 10 to satisfy the predicate")]
+    pub fn that_to_satisfy_does_not_accept_other_values() {
+        // Given a value not divisible by three
+        let value = 10;
+
+        // Expect to_satisfy to fail
+        expect(value).to_satisfy(|v| v % 3 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 10 to satisfy \"is prime\"")]
+    pub fn that_to_satisfy_described_includes_the_description() {
+        // Given a value that isn't prime
+        let value = 10;
+
+        // Expect to_satisfy_described to fail with the description
+        expect(value)
+            .to_satisfy_described("is prime", |n: &i32| *n > 1 && (2..*n).all(|d| n % d != 0));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that, when set, makes snapshot expectations write the actual value to
+/// the snapshot file instead of comparing against it
+pub const UPDATE_SNAPSHOTS_VAR: &str = "RXPECT_UPDATE_SNAPSHOTS";
+
+/// Extension trait for snapshot expectations.
+///
+/// Requires the `snapshot` feature.
+pub trait SnapshotExpectations<'e, T> {
+    /// Expect the pretty-printed `Debug` representation of the value to match the contents
+    /// of the snapshot file at `path`.
+    ///
+    /// Run the tests with the `RXPECT_UPDATE_SNAPSHOTS` environment variable set to create or
+    /// update the snapshot file.
+    /// ```no_run
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::SnapshotExpectations;
+    ///
+    /// expect(vec![1, 2, 3]).to_match_snapshot("tests/snapshots/numbers.snap");
+    /// ```
+    fn to_match_snapshot(self, path: impl AsRef<Path>) -> Self;
+}
+
+impl<'e, T, B> SnapshotExpectations<'e, T> for B
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_match_snapshot(self, path: impl AsRef<Path>) -> Self {
+        self.to_pass(SnapshotExpectation(path.as_ref().to_path_buf()))
+    }
+}
+
+/// Expectation for to_match_snapshot
+struct SnapshotExpectation(PathBuf);
+
+impl<T: Debug> Expectation<T> for SnapshotExpectation {
+    fn check(&self, value: &T) -> CheckResult {
+        check_snapshot(value, &self.0, "to_match_snapshot")
+    }
+}
+
+/// Compare the pretty-printed value against a snapshot file,
+/// or update the snapshot if [UPDATE_SNAPSHOTS_VAR] is set
+pub(crate) fn check_snapshot<T: Debug>(
+    value: &T,
+    path: &Path,
+    matcher: &'static str,
+) -> CheckResult {
+    let result = compare_snapshot(
+        &format!("{:#?}", value),
+        path,
+        std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some(),
+    );
+    match result.into_failure() {
+        None => CheckResult::Pass,
+        Some(failure) => CheckResult::FailWith(failure.with_matcher(matcher)),
+    }
+}
+
+fn compare_snapshot(actual: &str, path: &Path, update: bool) -> CheckResult {
+    if update {
+        let written = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(path, actual));
+        return match written {
+            Ok(_) => CheckResult::Pass,
+            Err(error) => CheckResult::FailWith(
+                Failure::new(format!(
+                    "Failed to update snapshot {}: {}",
+                    path.display(),
+                    error
+                ))
+                .with_actual(actual),
+            ),
+        };
+    }
+    match fs::read_to_string(path) {
+        Ok(expected) if expected == actual => CheckResult::Pass,
+        Ok(expected) => CheckResult::FailWith(
+            Failure::new(format!(
+                "Expected value to match snapshot {}\nexpected:\n{}\nactual:\n{}",
+                path.display(),
+                expected,
+                actual
+            ))
+            .with_expected(expected)
+            .with_actual(actual),
+        ),
+        Err(error) => CheckResult::FailWith(
+            Failure::new(format!(
+                "Failed to read snapshot {}: {}\nSet {} to create it\nactual:\n{}",
+                path.display(),
+                error,
+                UPDATE_SNAPSHOTS_VAR,
+                actual
+            ))
+            .with_actual(actual),
+        ),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{compare_snapshot, SnapshotExpectations};
+    use crate::expect;
+    use crate::CheckResult;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Create a path to a snapshot file that doesn't exist yet
+    pub(crate) fn snapshot_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!("rxpect-{}", std::process::id()))
+            .join(format!("{}.snap", name));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    pub fn that_to_match_snapshot_accepts_matching_snapshots() {
+        // Given a snapshot of a value
+        let path = snapshot_path("matching");
+        assert!(matches!(
+            compare_snapshot("[\n    1,\n    2,\n]", &path, true),
+            CheckResult::Pass
+        ));
+
+        // Expect the same value to match it
+        expect(vec![1, 2]).to_match_snapshot(&path);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected:\n\"foo\"\nactual:\n\"bar\"")]
+    pub fn that_to_match_snapshot_does_not_accept_differing_snapshots() {
+        // Given a snapshot of a value
+        let path = snapshot_path("differing");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "\"foo\"").unwrap();
+
+        // Expect another value to not match it
+        expect("bar").to_match_snapshot(&path);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to read snapshot")]
+    pub fn that_to_match_snapshot_does_not_accept_missing_snapshots() {
+        // Given a path without a snapshot
+        let path = snapshot_path("missing");
+
+        // Expect to_match_snapshot to fail
+        expect("foo").to_match_snapshot(&path);
+    }
+}
@@ -0,0 +1,828 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Extension trait for string expectations
+pub trait StringExpectations<'e, T> {
+    /// Expect the value to contain a substring
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("foobar").to_contain("oba");
+    /// ```
+    fn to_contain(self, substring: &'e str) -> Self;
+
+    /// Expect the value to contain a substring, ignoring case
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("Hello World").to_contain_ignoring_case("WORLD");
+    /// ```
+    fn to_contain_ignoring_case(self, substring: &'e str) -> Self;
+
+    /// Expect the value to equal another string, ignoring case
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("Hello World").to_equal_ignoring_case("hello world");
+    /// ```
+    fn to_equal_ignoring_case(self, other: &'e str) -> Self;
+
+    /// Expect the value to equal at least one of several strings, ignoring case
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("Pending").to_equal_one_of_ignoring_case(&["ACTIVE", "PENDING", "CLOSED"]);
+    /// ```
+    fn to_equal_one_of_ignoring_case(self, values: &'e [&'e str]) -> Self;
+
+    /// Expect the value to equal another string after leading and trailing whitespace
+    /// has been trimmed from the value
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("hello world\n").to_equal_trimmed("hello world");
+    /// ```
+    fn to_equal_trimmed(self, expected: &'e str) -> Self;
+
+    /// Expect the value to have `length` characters after leading and trailing whitespace
+    /// has been trimmed from it
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("  foo\n").to_have_trimmed_length(3);
+    /// ```
+    fn to_have_trimmed_length(self, length: usize) -> Self;
+
+    /// Expect the value to be an anagram of another string,
+    /// i.e. to consist of exactly the same characters in any order
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("listen").to_be_anagram_of("silent");
+    /// ```
+    fn to_be_anagram_of(self, other: &'e str) -> Self;
+
+    /// Expect the value to be an anagram of another string,
+    /// optionally ignoring whitespace and/or case
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("Dormitory").to_be_anagram_of_ignoring("dirty room", true, true);
+    /// ```
+    fn to_be_anagram_of_ignoring(
+        self,
+        other: &'e str,
+        ignore_whitespace: bool,
+        ignore_case: bool,
+    ) -> Self;
+
+    /// Expect the value to read the same forwards and backwards
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("racecar").to_be_palindrome();
+    /// ```
+    fn to_be_palindrome(self) -> Self;
+
+    /// Expect the value to read the same forwards and backwards,
+    /// optionally ignoring case and/or non-alphanumeric characters
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("A man, a plan, a canal: Panama").to_be_palindrome_ignoring(true, true);
+    /// ```
+    fn to_be_palindrome_ignoring(self, ignore_case: bool, ignore_non_alphanumeric: bool) -> Self;
+
+    /// Expect all brackets (`()`, `[]` and `{}`) in the value to be balanced and properly nested
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("fn main() { let v = [1, (2 + 3)]; }").to_have_balanced_brackets();
+    /// ```
+    fn to_have_balanced_brackets(self) -> Self;
+
+    /// Expect every non-empty line of the value to be indented by the corresponding number of
+    /// spaces in `levels`
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("fn main() {\n    println!();\n\n}").to_have_indentation(vec![0, 4, 0]);
+    /// ```
+    fn to_have_indentation(self, levels: Vec<usize>) -> Self;
+
+    /// Expect the value to match a regular expression.
+    ///
+    /// Requires the `regex` feature.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("2024-01-31").to_match_regex(r"^\d{4}-\d{2}-\d{2}$");
+    /// ```
+    #[cfg(feature = "regex")]
+    fn to_match_regex(self, pattern: &str) -> Self;
+
+    /// Expect the value to not match a regular expression.
+    ///
+    /// Requires the `regex` feature.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::StringExpectations;
+    ///
+    /// expect("foobar").to_not_match_regex(r"\d");
+    /// ```
+    #[cfg(feature = "regex")]
+    fn to_not_match_regex(self, pattern: &str) -> Self;
+}
+
+impl<'e, T, B> StringExpectations<'e, T> for B
+where
+    T: AsRef<str> + Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn to_contain(self, substring: &'e str) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_contain",
+            substring,
+            |actual: &T, substring| actual.as_ref().contains(substring),
+            |actual, substring| format!("Expected {:?} to contain {:?}", actual, substring),
+        ))
+    }
+
+    fn to_contain_ignoring_case(self, substring: &'e str) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_contain_ignoring_case",
+            substring,
+            |actual: &T, substring| {
+                actual
+                    .as_ref()
+                    .to_lowercase()
+                    .contains(&substring.to_lowercase())
+            },
+            |actual, substring| {
+                format!(
+                    "Expected {:?} to contain {:?} (ignoring case)",
+                    actual, substring
+                )
+            },
+        ))
+    }
+
+    fn to_equal_ignoring_case(self, other: &'e str) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_equal_ignoring_case",
+            other,
+            |actual: &T, other| actual.as_ref().to_lowercase() == other.to_lowercase(),
+            |actual, other| {
+                format!(
+                    "Expectation failed (expected == actual, ignoring case)\nexpected: {:?}\nactual: {:?}",
+                    other, actual
+                )
+            },
+        ))
+    }
+
+    fn to_equal_one_of_ignoring_case(self, values: &'e [&'e str]) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_equal_one_of_ignoring_case",
+            values,
+            |actual: &T, values| {
+                let actual = actual.as_ref().to_lowercase();
+                values.iter().any(|value| value.to_lowercase() == actual)
+            },
+            |actual, values| {
+                format!(
+                    "Expected {:?} to equal one of {:?} (ignoring case)",
+                    actual, values
+                )
+            },
+        ))
+    }
+
+    fn to_equal_trimmed(self, expected: &'e str) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_equal_trimmed",
+            expected,
+            |actual: &T, expected| actual.as_ref().trim() == *expected,
+            |actual, expected| {
+                format!(
+                    "Expectation failed (expected == actual, trimmed)\nexpected: {:?}\nactual: {:?}\nuntrimmed: {:?}",
+                    expected,
+                    actual.as_ref().trim(),
+                    actual
+                )
+            },
+        ))
+    }
+
+    fn to_have_trimmed_length(self, length: usize) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_trimmed_length",
+            length,
+            |actual: &T, length| actual.as_ref().trim().chars().count() == *length,
+            |actual, length| {
+                format!(
+                    "Expected {:?} to have length {} when trimmed, but it had {}\nuntrimmed: {:?}",
+                    actual.as_ref().trim(),
+                    length,
+                    actual.as_ref().trim().chars().count(),
+                    actual
+                )
+            },
+        ))
+    }
+
+    #[cfg(feature = "regex")]
+    fn to_match_regex(self, pattern: &str) -> Self {
+        self.to_pass(RegexExpectation::new(pattern, true))
+    }
+
+    #[cfg(feature = "regex")]
+    fn to_not_match_regex(self, pattern: &str) -> Self {
+        self.to_pass(RegexExpectation::new(pattern, false))
+    }
+
+    fn to_be_anagram_of(self, other: &'e str) -> Self {
+        self.to_be_anagram_of_ignoring(other, false, false)
+    }
+
+    fn to_be_anagram_of_ignoring(
+        self,
+        other: &'e str,
+        ignore_whitespace: bool,
+        ignore_case: bool,
+    ) -> Self {
+        self.to_pass(AnagramExpectation {
+            other,
+            ignore_whitespace,
+            ignore_case,
+        })
+    }
+
+    fn to_be_palindrome(self) -> Self {
+        self.to_be_palindrome_ignoring(false, false)
+    }
+
+    fn to_be_palindrome_ignoring(self, ignore_case: bool, ignore_non_alphanumeric: bool) -> Self {
+        self.to_pass(PalindromeExpectation {
+            ignore_case,
+            ignore_non_alphanumeric,
+        })
+    }
+
+    fn to_have_balanced_brackets(self) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_balanced_brackets",
+            (),
+            |actual: &T, _| bracket_imbalance(actual.as_ref()).is_none(),
+            |actual, _| {
+                format!(
+                    "Expected {:?} to have balanced brackets, but {}",
+                    actual,
+                    bracket_imbalance(actual.as_ref()).expect("an imbalance")
+                )
+            },
+        ))
+    }
+
+    fn to_have_indentation(self, levels: Vec<usize>) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_indentation",
+            levels,
+            |actual: &T, levels| indentation_mismatch(actual.as_ref(), levels).is_none(),
+            |actual, levels| {
+                format!(
+                    "Expected {:?} to have indentation {:?}, but {}",
+                    actual,
+                    levels,
+                    indentation_mismatch(actual.as_ref(), levels).expect("a mismatch")
+                )
+            },
+        ))
+    }
+}
+
+/// Find the first non-empty line with the wrong indentation, described for use in a failure message
+fn indentation_mismatch(value: &str, levels: &[usize]) -> Option<String> {
+    let lines = value
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect::<Vec<_>>();
+    let mismatch = lines
+        .iter()
+        .zip(levels)
+        .find_map(|((number, line), expected)| {
+            let indentation = line.len() - line.trim_start_matches(' ').len();
+            (indentation != *expected).then(|| {
+                format!(
+                    "line {} ({:?}) was indented by {} spaces instead of {}",
+                    number + 1,
+                    line,
+                    indentation,
+                    expected
+                )
+            })
+        });
+    mismatch.or_else(|| {
+        (lines.len() != levels.len()).then(|| {
+            format!(
+                "it had {} non-empty lines instead of {}",
+                lines.len(),
+                levels.len()
+            )
+        })
+    })
+}
+
+/// Expectation for to_match_regex and to_not_match_regex
+#[cfg(feature = "regex")]
+struct RegexExpectation {
+    pattern: String,
+    regex: Result<regex::Regex, regex::Error>,
+    should_match: bool,
+}
+
+#[cfg(feature = "regex")]
+impl RegexExpectation {
+    fn new(pattern: &str, should_match: bool) -> Self {
+        RegexExpectation {
+            pattern: pattern.to_owned(),
+            regex: regex::Regex::new(pattern),
+            should_match,
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T: AsRef<str> + Debug> Expectation<T> for RegexExpectation {
+    fn check(&self, value: &T) -> CheckResult {
+        let message = match &self.regex {
+            Err(error) => format!("Invalid regular expression /{}/: {}", self.pattern, error),
+            Ok(regex) if regex.is_match(value.as_ref()) == self.should_match => {
+                return CheckResult::Pass
+            }
+            Ok(_) => format!(
+                "Expected {:?} to {}match /{}/",
+                value,
+                if self.should_match { "" } else { "not " },
+                self.pattern
+            ),
+        };
+        CheckResult::FailWith(
+            Failure::new(message)
+                .with_expected(format!("/{}/", self.pattern))
+                .with_actual(format!("{:?}", value))
+                .with_matcher(if self.should_match {
+                    "to_match_regex"
+                } else {
+                    "to_not_match_regex"
+                }),
+        )
+    }
+}
+
+/// Find the first unbalanced bracket, described for use in a failure message
+fn bracket_imbalance(value: &str) -> Option<String> {
+    let mut open = Vec::new();
+    for (position, c) in value.chars().enumerate() {
+        match c {
+            '(' | '[' | '{' => open.push((position, c)),
+            ')' | ']' | '}' => {
+                let expected_opener = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                match open.pop() {
+                    None => {
+                        return Some(format!(
+                            "{:?} at position {} has no opening bracket",
+                            c, position
+                        ))
+                    }
+                    Some((open_position, opener)) if opener != expected_opener => {
+                        return Some(format!(
+                            "{:?} at position {} does not match {:?} at position {}",
+                            c, position, opener, open_position
+                        ))
+                    }
+                    Some(_) => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    open.first()
+        .map(|(position, c)| format!("{:?} at position {} is never closed", c, position))
+}
+
+/// Expectation for to_be_anagram_of
+struct AnagramExpectation<'e> {
+    other: &'e str,
+    ignore_whitespace: bool,
+    ignore_case: bool,
+}
+
+impl AnagramExpectation<'_> {
+    fn count_chars(&self, value: &str) -> BTreeMap<char, usize> {
+        let mut counts = BTreeMap::new();
+        value
+            .chars()
+            .filter(|c| !(self.ignore_whitespace && c.is_whitespace()))
+            .flat_map(|c| {
+                if self.ignore_case {
+                    c.to_lowercase().collect::<Vec<_>>()
+                } else {
+                    vec![c]
+                }
+            })
+            .for_each(|c| *counts.entry(c).or_insert(0) += 1);
+        counts
+    }
+}
+
+impl<T: AsRef<str> + Debug> Expectation<T> for AnagramExpectation<'_> {
+    fn check(&self, value: &T) -> CheckResult {
+        let actual = self.count_chars(value.as_ref());
+        let expected = self.count_chars(self.other);
+        if actual == expected {
+            return CheckResult::Pass;
+        }
+        let differences = expected
+            .keys()
+            .chain(actual.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter_map(|c| {
+                let expected_count = expected.get(c).copied().unwrap_or(0);
+                let actual_count = actual.get(c).copied().unwrap_or(0);
+                (expected_count != actual_count)
+                    .then(|| format!("  {c:?}: expected {expected_count}, actual {actual_count}"))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        CheckResult::FailWith(
+            Failure::new(format!(
+                "Expected {:?} to be an anagram of {:?}\ncharacter count differences:\n{}",
+                value, self.other, differences
+            ))
+            .with_expected(format!("{:?}", self.other))
+            .with_actual(format!("{:?}", value))
+            .with_matcher("to_be_anagram_of"),
+        )
+    }
+}
+
+/// Expectation for to_be_palindrome
+struct PalindromeExpectation {
+    ignore_case: bool,
+    ignore_non_alphanumeric: bool,
+}
+
+impl<T: AsRef<str> + Debug> Expectation<T> for PalindromeExpectation {
+    fn check(&self, value: &T) -> CheckResult {
+        let chars = value
+            .as_ref()
+            .chars()
+            .filter(|c| !self.ignore_non_alphanumeric || c.is_alphanumeric())
+            .flat_map(|c| {
+                if self.ignore_case {
+                    c.to_lowercase().collect::<Vec<_>>()
+                } else {
+                    vec![c]
+                }
+            })
+            .collect::<Vec<_>>();
+        let mismatch = chars
+            .iter()
+            .zip(chars.iter().rev())
+            .take(chars.len() / 2)
+            .position(|(a, b)| a != b);
+        match mismatch {
+            None => CheckResult::Pass,
+            Some(index) => {
+                let other = chars.len() - 1 - index;
+                CheckResult::FailWith(
+                    Failure::new(format!(
+                        "Expected {:?} to be a palindrome, but {:?} at position {} does not match {:?} at position {}",
+                        value, chars[index], index, chars[other], other
+                    ))
+                    .with_actual(format!("{:?}", value))
+                    .with_matcher("to_be_palindrome"),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StringExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_contain_accepts_substrings() {
+        // Given a string
+        let value = String::from("foobar");
+
+        // Expect to_contain to pass with a substring
+        expect(value).to_contain("oba");
+    }
+
+    #[test]
+    pub fn that_to_equal_trimmed_ignores_surrounding_whitespace() {
+        // Given output with surrounding whitespace
+        let value = String::from("\t foo bar\n");
+
+        // Expect to_equal_trimmed to pass
+        expect(value).to_equal_trimmed("foo bar");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expectation failed (expected == actual, trimmed)\nexpected: \"foo\"\nactual: \"bar\"\nuntrimmed: \" bar\\n\""
+    )]
+    pub fn that_to_equal_trimmed_shows_the_trimmed_and_untrimmed_value() {
+        // Given output with surrounding whitespace
+        let value = " bar\n";
+
+        // Expect to_equal_trimmed to fail for another string
+        expect(value).to_equal_trimmed("foo");
+    }
+
+    #[test]
+    pub fn that_to_have_trimmed_length_counts_characters() {
+        // Expect the length to be counted in characters without surrounding whitespace
+        expect("  åäö \n").to_have_trimmed_length(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"foo\" to have length 5 when trimmed, but it had 3")]
+    pub fn that_to_have_trimmed_length_does_not_accept_other_lengths() {
+        // Expect to_have_trimmed_length to fail when counting whitespace
+        expect(" foo ").to_have_trimmed_length(5);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn that_to_contain_does_not_accept_other_strings() {
+        // Given a string
+        let value = "foobar";
+
+        // Expect to_contain to fail with a string that isn't part of it
+        expect(value).to_contain("baz");
+    }
+
+    #[test]
+    pub fn that_to_contain_ignoring_case_accepts_substrings_in_any_case() {
+        // Given a string with mixed case
+        let value = "Hello World";
+
+        // Expect to_contain_ignoring_case to pass with a substring in another case
+        expect(value).to_contain_ignoring_case("WORLD");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"Hello World\" to contain \"Planet\" (ignoring case)")]
+    pub fn that_to_contain_ignoring_case_does_not_accept_other_strings() {
+        // Given a string with mixed case
+        let value = "Hello World";
+
+        // Expect to_contain_ignoring_case to fail with a string that isn't part of it
+        expect(value).to_contain_ignoring_case("Planet");
+    }
+
+    #[test]
+    pub fn that_to_equal_ignoring_case_accepts_strings_in_another_case() {
+        // Given a string with mixed case
+        let value = String::from("Hello World");
+
+        // Expect to_equal_ignoring_case to pass with the same string in another case
+        expect(value).to_equal_ignoring_case("hELLO wORLD");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected: \"Hello\"\nactual: \"Hello World\"")]
+    pub fn that_to_equal_ignoring_case_does_not_accept_other_strings() {
+        // Given a string
+        let value = "Hello World";
+
+        // Expect to_equal_ignoring_case to fail with a different string
+        expect(value).to_equal_ignoring_case("Hello");
+    }
+
+    #[test]
+    pub fn that_to_equal_one_of_ignoring_case_accepts_any_listed_value() {
+        // Given a status in an unexpected case
+        let value = String::from("pEnDiNg");
+
+        // Expect to_equal_one_of_ignoring_case to pass
+        expect(value).to_equal_one_of_ignoring_case(&["active", "PENDING"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected \"Deleted\" to equal one of [\"active\", \"PENDING\"] (ignoring case)"
+    )]
+    pub fn that_to_equal_one_of_ignoring_case_does_not_accept_other_values() {
+        // Given a status that isn't listed
+        let value = "Deleted";
+
+        // Expect to_equal_one_of_ignoring_case to fail
+        expect(value).to_equal_one_of_ignoring_case(&["active", "PENDING"]);
+    }
+
+    #[test]
+    pub fn that_to_be_anagram_of_accepts_anagrams() {
+        // Given a string
+        let value = "listen";
+
+        // Expect to_be_anagram_of to pass with an anagram
+        expect(value).to_be_anagram_of("silent");
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn that_to_be_anagram_of_does_not_accept_other_strings() {
+        // Given a string
+        let value = "listen";
+
+        // Expect to_be_anagram_of to fail with a string that has other characters
+        expect(value).to_be_anagram_of("silence");
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn that_to_be_anagram_of_is_case_and_whitespace_sensitive() {
+        // Given a string
+        let value = "Dormitory";
+
+        // Expect to_be_anagram_of to fail when case and whitespace differ
+        expect(value).to_be_anagram_of("dirty room");
+    }
+
+    #[test]
+    pub fn that_to_be_anagram_of_ignoring_can_ignore_case_and_whitespace() {
+        // Given a string
+        let value = String::from("Dormitory");
+
+        // Expect to_be_anagram_of_ignoring to pass when ignoring case and whitespace
+        expect(value).to_be_anagram_of_ignoring("dirty room", true, true);
+    }
+
+    #[test]
+    pub fn that_to_be_palindrome_accepts_palindromes() {
+        // Expect palindromes of odd and even length to pass
+        expect("racecar").to_be_palindrome();
+        expect("abba").to_be_palindrome();
+        expect("").to_be_palindrome();
+    }
+
+    #[test]
+    #[should_panic(expected = "'b' at position 1 does not match 'c' at position 2")]
+    pub fn that_to_be_palindrome_reports_the_first_mismatch() {
+        // Given a string that is not a palindrome
+        let value = "abca";
+
+        // Expect to_be_palindrome to fail
+        expect(value).to_be_palindrome();
+    }
+
+    #[test]
+    pub fn that_to_be_palindrome_ignoring_can_ignore_case_and_punctuation() {
+        // Given a palindrome with mixed case and punctuation
+        let value = "A man, a plan, a canal: Panama";
+
+        // Expect to_be_palindrome_ignoring to pass
+        expect(value).to_be_palindrome_ignoring(true, true);
+    }
+
+    #[test]
+    pub fn that_to_have_indentation_accepts_matching_indentation() {
+        // Given indented code with an empty line
+        let value = "fn main() {\n    if true {\n        return;\n    }\n\n}";
+
+        // Expect empty lines to be skipped
+        expect(value).to_have_indentation(vec![0, 4, 8, 4, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "line 3 (\"  }\") was indented by 2 spaces instead of 0")]
+    pub fn that_to_have_indentation_reports_the_first_wrong_line() {
+        // Given code with a wrongly indented line
+        let value = "{\n  foo\n  }";
+
+        // Expect to_have_indentation to fail on the closing brace
+        expect(value).to_have_indentation(vec![0, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "it had 2 non-empty lines instead of 3")]
+    pub fn that_to_have_indentation_does_not_accept_missing_lines() {
+        // Given code with fewer lines than expected
+        let value = "{\n  foo";
+
+        // Expect to_have_indentation to fail
+        expect(value).to_have_indentation(vec![0, 2, 0]);
+    }
+
+    #[test]
+    pub fn that_to_have_balanced_brackets_accepts_nested_brackets() {
+        // Expect nested and sequential brackets to pass
+        expect("({[]})[]{}").to_have_balanced_brackets();
+        expect("no brackets").to_have_balanced_brackets();
+    }
+
+    #[test]
+    #[should_panic(expected = "')' at position 3 does not match '[' at position 1")]
+    pub fn that_to_have_balanced_brackets_does_not_accept_interleaved_brackets() {
+        // Expect interleaved brackets to fail
+        expect("([a)]").to_have_balanced_brackets();
+    }
+
+    #[test]
+    #[should_panic(expected = "'}' at position 0 has no opening bracket")]
+    pub fn that_to_have_balanced_brackets_does_not_accept_unopened_brackets() {
+        // Expect a closing bracket without opener to fail
+        expect("}{").to_have_balanced_brackets();
+    }
+
+    #[test]
+    #[should_panic(expected = "'(' at position 0 is never closed")]
+    pub fn that_to_have_balanced_brackets_does_not_accept_unclosed_brackets() {
+        // Expect an unclosed bracket to fail
+        expect("(()").to_have_balanced_brackets();
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    pub fn that_to_match_regex_accepts_matching_strings() {
+        // Given a date string
+        let value = String::from("2024-01-31");
+
+        // Expect it to match a date pattern
+        expect(value).to_match_regex(r"^\d{4}-\d{2}-\d{2}$");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "Expected \"foo\" to match /^\\d+$/")]
+    pub fn that_to_match_regex_does_not_accept_other_strings() {
+        // Given a string without digits
+        let value = "foo";
+
+        // Expect to_match_regex to fail
+        expect(value).to_match_regex(r"^\d+$");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "Invalid regular expression /(foo/")]
+    pub fn that_to_match_regex_reports_invalid_patterns() {
+        // Given a string
+        let value = "foo";
+
+        // Expect to_match_regex to fail with an unclosed group
+        expect(value).to_match_regex("(foo");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    pub fn that_to_not_match_regex_accepts_other_strings() {
+        // Given a string without digits
+        let value = "foobar";
+
+        // Expect it to not match a digit pattern
+        expect(value).to_not_match_regex(r"\d");
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic(expected = "Expected \"foo1\" to not match /\\d/")]
+    pub fn that_to_not_match_regex_does_not_accept_matching_strings() {
+        // Given a string with a digit
+        let value = "foo1";
+
+        // Expect to_not_match_regex to fail
+        expect(value).to_not_match_regex(r"\d");
+    }
+}
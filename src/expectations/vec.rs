@@ -0,0 +1,120 @@
+use crate::expectations::predicate::PredicateExpectation;
+use crate::ExpectationBuilder;
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Extension trait for expectations on [Vec] that don't apply to other collections
+pub trait VecExpectations<'e, C> {
+    /// Expect the vec to have room for at least `capacity` items without reallocating
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::VecExpectations;
+    ///
+    /// expect(Vec::<u8>::with_capacity(16)).to_have_capacity_at_least(16);
+    /// ```
+    fn to_have_capacity_at_least(self, capacity: usize) -> Self;
+
+    /// Expect the vec to have exactly `length` items.
+    ///
+    /// Unlike [IterableCountExpectations::to_have_length](crate::expectations::IterableCountExpectations::to_have_length),
+    /// the failure message includes the capacity of the vec.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::VecExpectations;
+    ///
+    /// expect(vec![1, 2, 3]).to_have_vec_length(3);
+    /// ```
+    fn to_have_vec_length(self, length: usize) -> Self;
+}
+
+impl<'e, C, B> VecExpectations<'e, C> for B
+where
+    C: Debug + 'e,
+    B: ExpectationBuilder<'e, Vec<C>>,
+{
+    fn to_have_capacity_at_least(self, capacity: usize) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_capacity_at_least",
+            capacity,
+            |actual: &Vec<C>, capacity| actual.capacity() >= *capacity,
+            |actual, capacity| {
+                format!(
+                    "Expected vec to have a capacity of at least {}, but it had length {} and capacity {}\nactual: {:?}",
+                    capacity,
+                    actual.len(),
+                    actual.capacity(),
+                    actual
+                )
+            },
+        ))
+    }
+
+    fn to_have_vec_length(self, length: usize) -> Self {
+        self.to_pass(PredicateExpectation::new(
+            "to_have_vec_length",
+            length,
+            |actual: &Vec<C>, length| actual.len() == *length,
+            |actual, length| {
+                format!(
+                    "Expected vec to have length {}, but it had length {} and capacity {}\nactual: {:?}",
+                    length,
+                    actual.len(),
+                    actual.capacity(),
+                    actual
+                )
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VecExpectations;
+    use crate::expect;
+
+    #[test]
+    pub fn that_to_have_capacity_at_least_accepts_preallocated_vecs() {
+        // Given a vec with pre-allocated capacity
+        let mut values = Vec::with_capacity(10);
+        values.push(1);
+
+        // Expect to_have_capacity_at_least to pass
+        expect(values).to_have_capacity_at_least(10);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected vec to have a capacity of at least 1, but it had length 0 and capacity 0"
+    )]
+    pub fn that_to_have_capacity_at_least_does_not_accept_smaller_capacities() {
+        // Given a vec without capacity
+        let values: Vec<u8> = Vec::new();
+
+        // Expect to_have_capacity_at_least to fail
+        expect(values).to_have_capacity_at_least(1);
+    }
+
+    #[test]
+    pub fn that_to_have_vec_length_accepts_the_length() {
+        // Given a vec with more capacity than items
+        let mut values = Vec::with_capacity(8);
+        values.extend([1, 2]);
+
+        // Expect to_have_vec_length to check the number of items
+        expect(values).to_have_vec_length(2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected vec to have length 3, but it had length 2 and capacity 8\nactual: [1, 2]"
+    )]
+    pub fn that_to_have_vec_length_reports_the_length_and_capacity() {
+        // Given a vec with more capacity than items
+        let mut values = Vec::with_capacity(8);
+        values.extend([1, 2]);
+
+        // Expect to_have_vec_length to fail with both length and capacity
+        expect(values).to_have_vec_length(3);
+    }
+}
@@ -0,0 +1,215 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+/// The reason an expectation failed.
+///
+/// Besides the human readable message, a failure can carry structured data about what was
+/// expected, what the actual value was and which matcher failed, for use by tooling.
+/// A failure aggregating several failed expectations keeps them as its causes.
+/// Displaying a failure prints only the message.
+/// ```
+/// use rxpect::Failure;
+///
+/// let failure = Failure::new("Expected 1 to equal 2")
+///     .with_expected("2")
+///     .with_actual("1")
+///     .with_matcher("to_equal");
+/// assert_eq!("Expected 1 to equal 2", failure.to_string());
+/// assert_eq!(Some("to_equal"), failure.matcher());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    message: String,
+    expected: Option<String>,
+    actual: Option<String>,
+    matcher: Option<&'static str>,
+    causes: Vec<Failure>,
+}
+
+impl Failure {
+    /// Create a failure with only a message
+    pub fn new(message: impl Into<String>) -> Self {
+        Failure {
+            message: message.into(),
+            expected: None,
+            actual: None,
+            matcher: None,
+            causes: Vec::new(),
+        }
+    }
+
+    /// Record the expected value, usually formatted with `Debug`
+    pub fn with_expected(mut self, expected: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    /// Record the actual value, usually formatted with `Debug`
+    pub fn with_actual(mut self, actual: impl Into<String>) -> Self {
+        self.actual = Some(actual.into());
+        self
+    }
+
+    /// Record the name of the matcher that failed
+    pub fn with_matcher(mut self, matcher: &'static str) -> Self {
+        self.matcher = Some(matcher);
+        self
+    }
+
+    /// Record the failures this failure aggregates
+    pub fn with_causes(mut self, causes: impl IntoIterator<Item = Failure>) -> Self {
+        self.causes.extend(causes);
+        self
+    }
+
+    /// Replace the message, keeping the structured data.
+    /// Used by expectations that wrap the failure of nested expectations
+    pub(crate) fn map_message(mut self, f: impl FnOnce(&str) -> String) -> Self {
+        self.message = f(&self.message);
+        self
+    }
+
+    /// The human readable failure message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The expected value, if recorded
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    /// The actual value, if recorded
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_deref()
+    }
+
+    /// The name of the matcher that failed, if recorded
+    pub fn matcher(&self) -> Option<&'static str> {
+        self.matcher
+    }
+
+    /// The failures this failure aggregates, empty unless several expectations failed
+    pub fn causes(&self) -> &[Failure] {
+        &self.causes
+    }
+
+    /// The failure as a JSON object with the fields `message`, `expected`, `actual` and
+    /// `matcher`, where fields that weren't recorded are `null`, and `causes`, an array of the
+    /// aggregated failures as JSON objects.
+    ///
+    /// Requires the `json` feature.
+    /// ```
+    /// use rxpect::Failure;
+    ///
+    /// let failure = Failure::new("Expected 1 to be even").with_actual("1");
+    /// assert_eq!(
+    ///     r#"{"actual":"1","causes":[],"expected":null,"matcher":null,"message":"Expected 1 to be even"}"#,
+    ///     failure.to_json().to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "message": self.message,
+            "expected": self.expected,
+            "actual": self.actual,
+            "matcher": self.matcher,
+            "causes": self.causes.iter().map(Failure::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Self {
+        Failure::new(message)
+    }
+}
+
+impl From<&str> for Failure {
+    fn from(message: &str) -> Self {
+        Failure::new(message.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Failure;
+
+    #[test]
+    pub fn that_failures_display_only_the_message() {
+        // Given a failure with structured data
+        let failure = Failure::new("message")
+            .with_expected("1")
+            .with_actual("2")
+            .with_matcher("to_equal");
+
+        // Expect only the message to be displayed
+        assert_eq!("message", failure.to_string());
+    }
+
+    #[test]
+    pub fn that_failures_can_be_created_from_strings() {
+        // Given a message
+        let message = String::from("message");
+
+        // When a failure is created from it
+        let failure = Failure::from(message);
+
+        // Then it only has a message
+        assert_eq!(Failure::new("message"), failure);
+        assert_eq!(None, failure.expected());
+        assert_eq!(None, failure.actual());
+        assert_eq!(None, failure.matcher());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    pub fn that_failures_can_be_serialized_to_json() {
+        // Given a failure with structured data
+        let failure = Failure::new("message")
+            .with_expected("1")
+            .with_actual("2")
+            .with_matcher("to_equal");
+
+        // Expect all fields to be serialized
+        assert_eq!(
+            serde_json::json!({
+                "message": "message",
+                "expected": "1",
+                "actual": "2",
+                "matcher": "to_equal",
+                "causes": [],
+            }),
+            failure.to_json()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    pub fn that_causes_are_serialized_to_json() {
+        // Given a failure aggregating another failure
+        let failure = Failure::new("1 of 1 expectations failed")
+            .with_causes([Failure::new("message").with_matcher("to_be_even")]);
+
+        // Expect the cause to be serialized with all of its fields
+        assert_eq!(
+            serde_json::json!([{
+                "message": "message",
+                "expected": null,
+                "actual": null,
+                "matcher": "to_be_even",
+                "causes": [],
+            }]),
+            failure.to_json()["causes"]
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{expect, CheckResult, Expectation, ExpectationBuilder, Failure, RootExpectations};
+use alloc::format;
+use core::fmt::Debug;
+
+/// Create expectations for an operation that should be idempotent.
+///
+/// The operation is applied to `initial` once and then once more to a copy of the result.
+/// The returned expectations are on the state after the first application
+/// and will fail if the second application changed it.
+/// ```
+/// use rxpect::expect_idempotent;
+/// use rxpect::expectations::EqualityExpectations;
+///
+/// expect_idempotent(vec![3, 1, 2, 1], |v| {
+///     v.sort();
+///     v.dedup();
+/// })
+/// .to_equal(vec![1, 2, 3]);
+/// ```
+#[track_caller]
+pub fn expect_idempotent<'e, T>(initial: T, operation: impl Fn(&mut T)) -> RootExpectations<'e, T>
+where
+    T: Clone + PartialEq + Debug + 'e,
+{
+    let mut once = initial;
+    operation(&mut once);
+    let mut twice = once.clone();
+    operation(&mut twice);
+    expect(once).to_pass(IdempotencyExpectation(twice))
+}
+
+/// Expectation for expect_idempotent
+struct IdempotencyExpectation<T>(T);
+
+impl<T: PartialEq + Debug> Expectation<T> for IdempotencyExpectation<T> {
+    fn check(&self, value: &T) -> CheckResult {
+        if self.0.eq(value) {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected operation to be idempotent, but applying it twice changed the state\n once: `{:?}`\ntwice: `{:?}`",
+                    value, &self.0
+                ))
+                .with_expected(format!("{:?}", value))
+                .with_actual(format!("{:?}", &self.0))
+                .with_matcher("expect_idempotent"),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expect_idempotent;
+
+    #[test]
+    pub fn that_expect_idempotent_accepts_idempotent_operations() {
+        // Expect normalizing a string to be idempotent
+        expect_idempotent(String::from("  Foo "), |s| *s = s.trim().to_lowercase());
+    }
+
+    #[test]
+    #[should_panic(expected = " once: `1`\ntwice: `2`")]
+    pub fn that_expect_idempotent_does_not_accept_other_operations() {
+        // Expect incrementing a value to not be idempotent
+        expect_idempotent(0, |v| *v += 1);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{expect, RootExpectations};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Create expectations for the items produced by an iterator.
+///
+/// The iterator is consumed and its items are buffered in a [Vec] right away,
+/// so the iterable expectations can go over them as many times as they need to.
+/// This means it must be finite.
+/// ```
+/// use rxpect::expect_iter;
+/// use rxpect::expectations::{IterableCountExpectations, IterableEqualityExpectations};
+/// use std::collections::BTreeMap;
+///
+/// let map = BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]);
+/// expect_iter(map.keys()).to_have_length(3);
+/// expect_iter(map.values().map(|v| v * 2)).to_start_with_items(&[2, 4]);
+/// ```
+#[track_caller]
+pub fn expect_iter<'e, It>(iter: It) -> RootExpectations<'e, Vec<It::Item>>
+where
+    It: Iterator,
+    It::Item: Debug,
+{
+    expect(iter.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expect_iter;
+    use crate::expectations::{IterableCountExpectations, IterableOrderExpectations};
+
+    #[test]
+    pub fn that_expect_iter_buffers_the_items() {
+        // Given an iterator that can only be consumed once
+        let iter = (1..=3).map(|i| i * i);
+
+        // Expect several expectations to see all of the items
+        expect_iter(iter)
+            .to_have_length(3)
+            .to_be_strictly_increasing();
+    }
+
+    #[test]
+    #[should_panic(expected = "[1, 4, 9]")]
+    pub fn that_expect_iter_reports_the_buffered_items() {
+        // Given an iterator
+        let iter = (1..=3).map(|i| i * i);
+
+        // Expect a failure to report the items
+        expect_iter(iter).to_have_length(2);
+    }
+}
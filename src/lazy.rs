@@ -0,0 +1,103 @@
+use crate::{expect, CheckResult, Expectation, ExpectationBuilder, Failure, RootExpectations};
+use alloc::borrow::ToOwned;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::fmt::Debug;
+
+/// Probe used to observe when an iterator evaluates its items.
+///
+/// Passed to the closure given to [expect_lazy], call [touch](EvaluationProbe::touch)
+/// from inside the iterator pipeline wherever work is done.
+#[derive(Clone, Debug, Default)]
+pub struct EvaluationProbe(Rc<Cell<bool>>);
+
+impl EvaluationProbe {
+    /// Record that the iterator did some work
+    pub fn touch(&self) {
+        self.0.set(true);
+    }
+
+    fn touched(&self) -> bool {
+        self.0.get()
+    }
+}
+
+/// Create expectations for a lazy iterator.
+///
+/// The iterator is built by calling `build` with an [EvaluationProbe], which the iterator is
+/// expected to touch when (and only when) it is consumed.
+/// The iterator is then consumed and expectations can be added to the collected items.
+/// ```
+/// use rxpect::expect_lazy;
+/// use rxpect::expectations::EqualityExpectations;
+///
+/// expect_lazy(|probe| (1..=3).inspect(move |_| probe.touch()).map(|x| x * 2))
+///     .to_equal(vec![2, 4, 6]);
+/// ```
+#[track_caller]
+pub fn expect_lazy<'e, I>(
+    build: impl FnOnce(EvaluationProbe) -> I,
+) -> RootExpectations<'e, Vec<I::Item>>
+where
+    I: Iterator,
+    I::Item: Debug,
+{
+    let probe = EvaluationProbe::default();
+    let iterator = build(probe.clone());
+    let evaluated_when_built = probe.touched();
+    let items = iterator.collect::<Vec<_>>();
+    expect(items).to_pass(LazinessExpectation {
+        evaluated_when_built,
+        evaluated_when_consumed: probe.touched(),
+    })
+}
+
+/// Expectation for expect_lazy
+struct LazinessExpectation {
+    evaluated_when_built: bool,
+    evaluated_when_consumed: bool,
+}
+
+impl<T: Debug> Expectation<T> for LazinessExpectation {
+    fn check(&self, _: &T) -> CheckResult {
+        let message = if self.evaluated_when_built {
+            "Expected iterator to be lazy, but it was evaluated while being built"
+        } else if !self.evaluated_when_consumed {
+            "Expected iterator to be evaluated when consumed, but the probe was never touched"
+        } else {
+            return CheckResult::Pass;
+        };
+        CheckResult::FailWith(Failure::new(message.to_owned()).with_matcher("expect_lazy"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expect_lazy;
+
+    #[test]
+    pub fn that_expect_lazy_accepts_lazy_iterators() {
+        // Expect an iterator adapter that only touches the probe when iterated to pass
+        expect_lazy(|probe| (0..3).inspect(move |_| probe.touch()));
+    }
+
+    #[test]
+    #[should_panic(expected = "evaluated while being built")]
+    pub fn that_expect_lazy_does_not_accept_eager_iterators() {
+        // Expect an iterator that is evaluated when built to fail
+        expect_lazy(|probe| {
+            (0..3)
+                .inspect(move |_| probe.touch())
+                .collect::<Vec<_>>()
+                .into_iter()
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "probe was never touched")]
+    pub fn that_expect_lazy_does_not_accept_untouched_probes() {
+        // Expect an iterator that never touches the probe to fail
+        expect_lazy(|_| 0..3);
+    }
+}
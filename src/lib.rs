@@ -41,23 +41,111 @@
 //! expected: `3`
 //! actual: `2`'
 //! ```
-pub mod expectations;
+//!
+//! With the `ide-compat` feature, failures of `to_equal` also label the values `left` and
+//! `right` like `assert_eq!` does, so that test runners in IDEs can show a diff.
+//! With the `pretty-diff` feature, they print a diff of the pretty-printed values instead,
+//! colored when the `RXPECT_COLOR` environment variable is set and `NO_COLOR` isn't.
+//! The two don't combine: when both are enabled, `ide-compat` takes precedence and
+//! no diff is printed.
+//!
+//! ## `no_std`
+//!
+//! The crate builds under `no_std` with `alloc` when the default `std` feature is disabled.
+//! This keeps [expect], [expect_ref], projections, aspects, negation, disjunction and the
+//! expectations on values, numbers, strings, options, results and collections.
+//! The expectations that need the standard library are only available with the `std` feature:
+//! [eventually](crate::eventually()), [expect_timed], [register_matcher] and the named matchers,
+//! histograms, and the expectations on paths and locks.
+//! The `fs`, `json`, `pretty-diff`, `regex` and `snapshot` features enable `std`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt::Debug;
+
+pub mod aspect;
+mod disjunction;
+#[cfg(feature = "std")]
+mod eventually;
 mod expectation_list;
+mod expectation_set;
+pub mod expectations;
+mod failure;
+mod idempotent;
+mod iter;
+mod lazy;
+mod negation;
+mod pattern;
 mod projection;
+#[cfg(feature = "std")]
+mod registry;
 mod root;
+#[cfg(feature = "std")]
+mod timed;
+mod written;
 
-pub use projection::ExpectProjection;
-pub use root::RootExpectations;
-use std::fmt::Debug;
+pub use aspect::{AspectExpectations, RelateExpectations};
+pub use disjunction::{Branch, DisjunctionExpectations};
+#[cfg(feature = "std")]
+pub use eventually::{eventually, EventualExpectations};
+pub use expectation_set::{ExpectationSet, SatisfyAllExpectations};
+pub use failure::Failure;
+pub use idempotent::expect_idempotent;
+pub use iter::expect_iter;
+pub use lazy::{expect_lazy, EvaluationProbe};
+pub use negation::NegationExpectations;
+#[doc(hidden)]
+pub use pattern::expect_matching as __expect_matching;
+pub use projection::{ExpectProjection, TryExpectProjection};
+#[cfg(feature = "std")]
+pub use registry::register_matcher;
+pub use root::{Borrowed, Owned, RootExpectations};
+#[cfg(feature = "std")]
+pub use timed::{expect_timed, TimedExpectations};
+pub use written::expect_written;
 
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
 pub struct ReadmeDoctests;
 
+/// The result of checking an expectation
 #[derive(Clone, Debug)]
 pub enum CheckResult {
     Pass,
+    /// The expectation failed with a message
     Fail(String),
+    /// The expectation failed with a [Failure] that carries structured data besides the message
+    FailWith(Failure),
+}
+
+impl CheckResult {
+    /// The failure if the expectation failed, where a plain message becomes a [Failure]
+    /// without structured data
+    /// ```
+    /// use rxpect::{CheckResult, Failure};
+    ///
+    /// let result = CheckResult::Fail("Expected 1 to be even".to_string());
+    /// assert_eq!(Some(Failure::new("Expected 1 to be even")), result.into_failure());
+    /// assert_eq!(None, CheckResult::Pass.into_failure());
+    /// ```
+    pub fn into_failure(self) -> Option<Failure> {
+        match self {
+            CheckResult::Pass => None,
+            CheckResult::Fail(message) => Some(Failure::new(message)),
+            CheckResult::FailWith(failure) => Some(failure),
+        }
+    }
+
+    /// Replace the message of a failure, keeping any structured data.
+    /// Used by expectations that wrap the failure of nested expectations
+    pub(crate) fn map_message(self, f: impl FnOnce(&str) -> String) -> Self {
+        match self {
+            CheckResult::Pass => CheckResult::Pass,
+            CheckResult::Fail(message) => CheckResult::Fail(f(&message)),
+            CheckResult::FailWith(failure) => CheckResult::FailWith(failure.map_message(f)),
+        }
+    }
 }
 
 /// An expectation on a value
@@ -68,25 +156,144 @@ pub trait Expectation<T: Debug> {
     fn check(&self, value: &T) -> CheckResult;
 }
 
-/// Trait to enable fluent building of expectations
+/// Trait to enable fluent building of expectations.
+///
+/// Custom expectations are added by implementing [Expectation] and exposing it through an
+/// extension trait that is implemented for every builder, the same way the built-in ones are:
+/// ```
+/// use rxpect::{expect, CheckResult, Expectation, ExpectationBuilder};
+///
+/// struct EvenExpectation;
+///
+/// impl Expectation<u32> for EvenExpectation {
+///     fn check(&self, value: &u32) -> CheckResult {
+///         if value % 2 == 0 {
+///             CheckResult::Pass
+///         } else {
+///             CheckResult::Fail(format!("Expected {} to be even", value))
+///         }
+///     }
+/// }
+///
+/// pub trait EvenExpectations {
+///     fn to_be_even(self) -> Self;
+/// }
+///
+/// impl<'e, B: ExpectationBuilder<'e, u32>> EvenExpectations for B {
+///     fn to_be_even(self) -> Self {
+///         self.to_pass(EvenExpectation)
+///     }
+/// }
+///
+/// expect(4).to_be_even();
+/// ```
+///
+/// Builders only need to implement [add_expectation](ExpectationBuilder::add_expectation),
+/// the consuming [to_pass](ExpectationBuilder::to_pass) is provided on top of it,
+/// so matchers can be written against either style.
 pub trait ExpectationBuilder<'e, T: Debug> {
+    /// Add an expectation to the builder in place.
+    /// This is intended for code that holds on to a builder by mutable reference
+    fn add_expectation(&mut self, expectation: impl Expectation<T> + 'e) -> &mut Self;
+
     /// Expect the value to pass an expectation
     /// This is intended to be used in extension methods to add expectations to the builder
-    fn to_pass(self, expectation: impl Expectation<T> + 'e) -> Self;
+    fn to_pass(mut self, expectation: impl Expectation<T> + 'e) -> Self
+    where
+        Self: Sized,
+    {
+        self.add_expectation(expectation);
+        self
+    }
+
+    /// Expect the value to pass a custom expectation.
+    ///
+    /// This is an alias of [to_pass](ExpectationBuilder::to_pass) that reads better
+    /// when using a one-off expectation directly in a test
+    /// ```
+    /// use rxpect::{expect, CheckResult, Expectation, ExpectationBuilder};
+    ///
+    /// struct NotEmpty;
+    ///
+    /// impl Expectation<String> for NotEmpty {
+    ///     fn check(&self, value: &String) -> CheckResult {
+    ///         match value.is_empty() {
+    ///             true => CheckResult::Fail("Expected string to not be empty".to_owned()),
+    ///             false => CheckResult::Pass,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// expect("foo".to_string()).custom(NotEmpty);
+    /// ```
+    fn custom(self, expectation: impl Expectation<T> + 'e) -> Self
+    where
+        Self: Sized,
+    {
+        self.to_pass(expectation)
+    }
+
+    /// Group expectations in a long chain for readability.
+    ///
+    /// The builder is passed through `config` and returned as is, so this adds nothing
+    /// that chaining the expectations directly wouldn't.
+    /// ```
+    /// use rxpect::{expect, ExpectationBuilder};
+    /// use rxpect::expectations::{IterableCountExpectations, IterableOrderExpectations};
+    ///
+    /// expect(vec![1, 2, 3])
+    ///     .also(|size| size.to_have_length(3))
+    ///     .also(|order| order.to_be_sorted().to_be_strictly_increasing());
+    /// ```
+    fn also(self, config: impl FnOnce(Self) -> Self) -> Self
+    where
+        Self: Sized,
+    {
+        config(self)
+    }
 }
 
 /// Create expectations for a value.
 /// Used as an entrypoint for fluently building expectations
+///
+/// Failure messages include the location of the call to `expect`.
 /// ```
 /// use rxpect::expect;
 /// use rxpect::expectations::EqualityExpectations;
 ///
 /// expect(1).to_equal(1);
 /// ```
+#[track_caller]
 pub fn expect<'e, T: Debug>(value: T) -> RootExpectations<'e, T> {
     RootExpectations::new(value)
 }
 
+/// Create expectations for a borrowed value, so that it can be used again afterwards.
+///
+/// The expectations are on the value itself rather than on the reference,
+/// so expectations such as the iterable ones work the same as with [expect].
+/// Methods that move the value out of the expectations, such as `unwrap_ok`, aren't available.
+/// ```
+/// use rxpect::expect_ref;
+/// use rxpect::expectations::IterableEqualityExpectations;
+///
+/// let values = vec![1, 2, 3];
+/// expect_ref(&values).to_have_item_at(0, 1);
+/// drop(values);
+/// ```
+///
+/// Moving the value out is rejected at compile time
+/// ```compile_fail
+/// use rxpect::expect_ref;
+///
+/// let result: Result<u32, ()> = Ok(7);
+/// let value = expect_ref(&result).unwrap_ok();
+/// ```
+#[track_caller]
+pub fn expect_ref<'e, T: Debug>(value: &'e T) -> RootExpectations<'e, T, Borrowed> {
+    RootExpectations::new_borrowed(value)
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use crate::{CheckResult, Expectation};
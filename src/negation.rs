@@ -0,0 +1,96 @@
+use crate::expectation_list::ExpectationList;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+struct NegatedExpectations<'e, T>(ExpectationList<'e, T>);
+
+impl<'e, T: Debug + 'e> Expectation<T> for NegatedExpectations<'e, T> {
+    fn check(&self, value: &T) -> CheckResult {
+        let passed: Vec<usize> = self
+            .0
+            .check_each(value)
+            .enumerate()
+            .filter(|(_, result)| matches!(result, CheckResult::Pass))
+            .map(|(index, _)| index)
+            .collect();
+        if passed.is_empty() {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected the negated expectation(s) to fail, but {} of {} passed\npassed expectations at index: {:?}\nactual: {:?}",
+                    passed.len(),
+                    self.0.expectation_count(),
+                    passed,
+                    value
+                ))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("not"),
+            )
+        }
+    }
+}
+
+pub trait NegationExpectations<'e, T, B>
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    /// Negate expectations, passing only if every one of them fails
+    ///
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::expectations::EqualityExpectations;
+    /// use rxpect::NegationExpectations;
+    ///
+    /// expect(4).not(|it| it.to_equal(5));
+    /// ```
+    fn not(self, config: impl FnOnce(B) -> B) -> Self;
+}
+
+impl<'e, T, B> NegationExpectations<'e, T, ExpectationList<'e, T>> for B
+where
+    T: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn not(self, config: impl FnOnce(ExpectationList<'e, T>) -> ExpectationList<'e, T>) -> Self {
+        self.to_pass(NegatedExpectations(config(ExpectationList::new())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::TestExpectation;
+    use crate::{expect, CheckResult, ExpectationBuilder, NegationExpectations};
+
+    #[test]
+    pub fn that_not_passes_when_all_expectations_fail() {
+        // Given two expectations that both fail
+        let (expectation1, expected1) = TestExpectation::new(CheckResult::Fail("1".to_owned()));
+        let (expectation2, expected2) = TestExpectation::new(CheckResult::Fail("2".to_owned()));
+
+        // When they are negated and checked
+        expect(true)
+            .not(|it| it.to_pass(expectation1).to_pass(expectation2))
+            .check();
+
+        // Then both expectations were run
+        assert!(*expected1.lock().unwrap());
+        assert!(*expected2.lock().unwrap());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected the negated expectation(s) to fail, but 1 of 2 passed\npassed expectations at index: [1]\nactual: true"
+    )]
+    pub fn that_not_fails_when_any_expectation_passes() {
+        // Given one expectation that fails and one that passes
+        let (expectation1, _) = TestExpectation::new(CheckResult::Fail("1".to_owned()));
+        let (expectation2, _) = TestExpectation::new(CheckResult::Pass);
+
+        // Expect negating them to fail
+        expect(true).not(|it| it.to_pass(expectation1).to_pass(expectation2));
+    }
+}
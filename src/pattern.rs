@@ -0,0 +1,112 @@
+use crate::{expect, CheckResult, Expectation, ExpectationBuilder, Failure, RootExpectations};
+use alloc::format;
+use core::fmt::Debug;
+
+/// Create expectations for a value that must match a pattern, such as an enum variant.
+///
+/// The pattern can have a guard, and the failure message names the pattern as it was written.
+/// Like [expect](crate::expect), this returns the expectations so more can be chained.
+/// ```
+/// use rxpect::expect_matches;
+/// use rxpect::expectations::EqualityExpectations;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Message {
+///     Connect { host: String },
+///     Data(Vec<u8>),
+///     Disconnect,
+/// }
+///
+/// let message = Message::Connect { host: "localhost".to_string() };
+/// expect_matches!(message, Message::Connect { .. });
+/// expect_matches!(Message::Data(vec![1, 2]), Message::Data(data) if data.len() == 2);
+/// expect_matches!(Message::Disconnect, Message::Disconnect).to_not_equal(Message::Data(vec![]));
+/// ```
+#[macro_export]
+macro_rules! expect_matches {
+    ($value:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        $crate::__expect_matching(
+            $value,
+            stringify!($pattern $(if $guard)?),
+            |value| matches!(value, $pattern $(if $guard)?),
+        )
+    };
+}
+
+/// Entry point for [expect_matches], taking the value first so the type of the closure is known
+#[doc(hidden)]
+#[track_caller]
+pub fn expect_matching<'e, T: Debug>(
+    value: T,
+    pattern: &'static str,
+    matches: impl Fn(&T) -> bool + 'e,
+) -> RootExpectations<'e, T> {
+    expect(value).to_pass(PatternExpectation { pattern, matches })
+}
+
+/// Expectation for expect_matches
+struct PatternExpectation<F> {
+    pattern: &'static str,
+    matches: F,
+}
+
+impl<T: Debug, F: Fn(&T) -> bool> Expectation<T> for PatternExpectation<F> {
+    fn check(&self, value: &T) -> CheckResult {
+        if (self.matches)(value) {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected {:?} to match the pattern `{}`",
+                    value, self.pattern
+                ))
+                .with_expected(self.pattern)
+                .with_actual(format!("{:?}", value))
+                .with_matcher("expect_matches"),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug)]
+    enum Message {
+        Connect { host: &'static str },
+        Data(u8),
+        Disconnect,
+    }
+
+    #[test]
+    pub fn that_expect_matches_accepts_matching_variants() {
+        // Expect variants with and without data to match
+        expect_matches!(
+            Message::Connect { host: "localhost" },
+            Message::Connect { host: "localhost" }
+        );
+        expect_matches!(Message::Data(1), Message::Data(_));
+        expect_matches!(Message::Disconnect, Message::Disconnect,);
+    }
+
+    #[test]
+    pub fn that_expect_matches_supports_guards() {
+        // Expect the guard to be evaluated on the bound values
+        expect_matches!(Message::Data(2), Message::Data(n) if n % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Disconnect to match the pattern `Message::Connect { .. }`")]
+    pub fn that_expect_matches_names_the_pattern() {
+        // Expect another variant to fail with the pattern in the message
+        expect_matches!(Message::Disconnect, Message::Connect { .. });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected Data(3) to match the pattern `Message::Data(n) if n % 2 == 0`"
+    )]
+    pub fn that_expect_matches_does_not_accept_failing_guards() {
+        // Expect a failing guard to fail
+        expect_matches!(Message::Data(3), Message::Data(n) if n % 2 == 0);
+    }
+}
@@ -1,7 +1,11 @@
 use crate::expectation_list::ExpectationList;
-use crate::{CheckResult, Expectation, ExpectationBuilder};
-use std::fmt::Debug;
-use std::marker::PhantomData;
+use crate::{CheckResult, Expectation, ExpectationBuilder, Failure};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt::Debug;
+use core::marker::PhantomData;
 
 struct ProjectedExpectations<'e, F, T, U>
 where
@@ -22,20 +26,53 @@ where
 {
     fn check(&self, value: &T) -> CheckResult {
         let projected = (self.projection)(value);
-        match self.expectations.check(&projected) {
-            CheckResult::Fail(message) => CheckResult::Fail(
-                message
-                    .lines()
-                    .map(|line| "  ".to_string() + line)
-                    .fold(String::new(), |a, b| a + &b + "\n")
-                    .trim_end()
-                    .to_owned(),
+        self.expectations.check(&projected).map_message(indent)
+    }
+}
+
+struct TryProjectedExpectations<'e, F, T, U>
+where
+    F: Fn(&T) -> Result<U, String>,
+    T: Debug,
+    U: Debug + 'e,
+{
+    projection: F,
+    expectations: ExpectationList<'e, U>,
+    _t: PhantomData<&'e T>,
+}
+
+impl<'e, F, T, U> Expectation<T> for TryProjectedExpectations<'e, F, T, U>
+where
+    F: (Fn(&T) -> Result<U, String>) + 'e,
+    T: Debug,
+    U: Debug + 'e,
+{
+    fn check(&self, value: &T) -> CheckResult {
+        match (self.projection)(value) {
+            Ok(projected) => self.expectations.check(&projected).map_message(indent),
+            Err(error) => CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected projection to succeed, but it failed:\n{}\nactual: {:?}",
+                    indent(&error),
+                    value
+                ))
+                .with_actual(format!("{:?}", value))
+                .with_matcher("try_projected_by"),
             ),
-            pass => pass,
         }
     }
 }
 
+/// Indent every line of a message by two spaces
+pub(crate) fn indent(message: &str) -> String {
+    message
+        .lines()
+        .map(|line| "  ".to_string() + line)
+        .fold(String::new(), |a, b| a + &b + "\n")
+        .trim_end()
+        .to_owned()
+}
+
 pub trait ExpectProjection<'e, F, T, U, B>
 where
     F: (Fn(&T) -> U) + 'e,
@@ -82,12 +119,60 @@ where
     }
 }
 
+pub trait TryExpectProjection<'e, F, T, U, B>
+where
+    F: (Fn(&T) -> Result<U, String>) + 'e,
+    T: Debug + 'e,
+    U: Debug + 'e,
+    B: ExpectationBuilder<'e, U>,
+{
+    /// Add expectations on a value projected by a projection that can fail.
+    ///
+    /// If the projection returns an error, the expectation fails with that error.
+    ///
+    /// ```
+    /// use rxpect::expect;
+    /// use rxpect::expectations::EqualityExpectations;
+    /// use rxpect::TryExpectProjection;
+    ///
+    /// expect("42").try_projected_by(
+    ///     |it| it.parse::<u32>().map_err(|e| e.to_string()),
+    ///     |number| number.to_equal(42),
+    /// );
+    /// ```
+    fn try_projected_by(self, projection: F, config: impl FnOnce(B) -> B) -> Self;
+}
+
+impl<'e, F, T, U, B> TryExpectProjection<'e, F, T, U, ExpectationList<'e, U>> for B
+where
+    F: (Fn(&T) -> Result<U, String>) + 'e,
+    T: Debug + 'e,
+    U: Debug + 'e,
+    B: ExpectationBuilder<'e, T>,
+{
+    fn try_projected_by(
+        self,
+        projection: F,
+        config: impl FnOnce(ExpectationList<'e, U>) -> ExpectationList<'e, U>,
+    ) -> Self {
+        let expectations = config(ExpectationList::new());
+        self.to_pass(TryProjectedExpectations {
+            projection,
+            expectations,
+            _t: Default::default(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::expectation_list::ExpectationList;
     use crate::projection::ProjectedExpectations;
     use crate::tests::TestExpectation;
-    use crate::{expect, CheckResult, ExpectProjection, Expectation, ExpectationBuilder};
+    use crate::{
+        expect, CheckResult, ExpectProjection, Expectation, ExpectationBuilder, Failure,
+        TryExpectProjection,
+    };
 
     #[test]
     pub fn that_projection_runs_all_expectations() {
@@ -135,6 +220,35 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn that_projection_keeps_structured_failure_data() {
+        // Given expectation that fails with structured data
+        let (expectation, _) = TestExpectation::new(CheckResult::FailWith(
+            Failure::new("failed")
+                .with_actual("1")
+                .with_matcher("to_equal"),
+        ));
+
+        // And an aggregated projection expectation
+        let mut projected = ProjectedExpectations {
+            expectations: ExpectationList::new(),
+            projection: |_| 1,
+            _t: Default::default(),
+        };
+        projected.expectations.push(expectation);
+
+        // When the aggregated expectation is checked
+        let result = projected.check(&true);
+
+        // Then the structured data is kept
+        if let CheckResult::FailWith(failure) = result {
+            assert_eq!(Some("1"), failure.actual());
+            assert_eq!(Some("to_equal"), failure.matcher());
+        } else {
+            panic!("Result was a pass when failure was expected");
+        }
+    }
+
     #[test]
     pub fn that_projections_can_be_nested_deeply() {
         // Given an expectation that passes
@@ -157,4 +271,49 @@ mod tests {
         // Then the expectation was checked
         assert!(*expected.lock().unwrap());
     }
+
+    #[test]
+    pub fn that_try_projection_runs_expectations_on_the_projected_value() {
+        // Given an expectation that passes
+        let (expectation, expected) = TestExpectation::new(CheckResult::Pass);
+
+        // When it's used on a projection that succeeds
+        expect("7")
+            .try_projected_by(
+                |it| it.parse::<u8>().map_err(|e| e.to_string()),
+                |it| it.to_pass(expectation),
+            )
+            .check();
+
+        // Then the expectation was run
+        assert!(*expected.lock().unwrap());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected projection to succeed, but it failed:\n  invalid digit found in string\nactual: \"foo\""
+    )]
+    pub fn that_try_projection_fails_when_the_projection_fails() {
+        // Given an expectation that passes
+        let (expectation, _) = TestExpectation::new(CheckResult::Pass);
+
+        // Expect a failing projection to fail with its error
+        expect("foo").try_projected_by(
+            |it| it.parse::<u8>().map_err(|e| e.to_string()),
+            |it| it.to_pass(expectation),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "this\n  failed")]
+    pub fn that_try_projection_indents_failures() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("this\nfailed".to_string()));
+
+        // Expect the failure to be indented
+        expect("1").try_projected_by(
+            |it| it.parse::<u8>().map_err(|e| e.to_string()),
+            |it| it.to_pass(expectation),
+        );
+    }
 }
@@ -0,0 +1,103 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// A matcher stored in the registry, typed by the value it accepts
+pub(crate) struct NamedMatcher<T>(Box<dyn Fn(&T) -> bool + Send + Sync>);
+
+impl<T> NamedMatcher<T> {
+    pub(crate) fn matches(&self, value: &T) -> bool {
+        (self.0)(value)
+    }
+}
+
+/// Outcome of looking up a matcher by name
+pub(crate) enum Lookup<T> {
+    Found(Arc<NamedMatcher<T>>),
+    WrongType,
+    Missing,
+}
+
+type Registry = Mutex<HashMap<String, Arc<dyn Any + Send + Sync>>>;
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Register a named matcher that can later be used with
+/// [to_satisfy_named](crate::expectations::NamedMatcherExpectations::to_satisfy_named).
+///
+/// The registry is global, so matchers registered anywhere in a test binary are available to all
+/// tests in it. Registering a matcher under a name that is already taken replaces the old matcher.
+/// ```
+/// use rxpect::{expect, register_matcher};
+/// use rxpect::expectations::NamedMatcherExpectations;
+///
+/// register_matcher("positive_even", |v: &i32| *v > 0 && v % 2 == 0);
+/// expect(4).to_satisfy_named("positive_even");
+/// ```
+pub fn register_matcher<T: 'static>(
+    name: impl Into<String>,
+    matcher: impl Fn(&T) -> bool + Send + Sync + 'static,
+) {
+    let matcher: Arc<dyn Any + Send + Sync> = Arc::new(NamedMatcher(Box::new(matcher)));
+    registry()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(name.into(), matcher);
+}
+
+/// Look up a previously registered matcher for values of type `T`
+pub(crate) fn lookup_matcher<T: 'static>(name: &str) -> Lookup<T> {
+    let matcher = registry()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(name)
+        .cloned();
+    match matcher.map(|m| m.downcast::<NamedMatcher<T>>()) {
+        Some(Ok(matcher)) => Lookup::Found(matcher),
+        Some(Err(_)) => Lookup::WrongType,
+        None => Lookup::Missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lookup_matcher, register_matcher, Lookup};
+
+    #[test]
+    pub fn that_registered_matchers_can_be_looked_up() {
+        // Given a registered matcher
+        register_matcher("registry_test_is_one", |v: &i32| *v == 1);
+
+        // When it is looked up
+        let lookup = lookup_matcher::<i32>("registry_test_is_one");
+
+        // Then it is found and works
+        if let Lookup::Found(matcher) = lookup {
+            assert!(matcher.matches(&1));
+            assert!(!matcher.matches(&2));
+        } else {
+            panic!("Matcher was not found");
+        }
+    }
+
+    #[test]
+    pub fn that_lookup_distinguishes_wrong_type_from_missing() {
+        // Given a matcher registered for i32
+        register_matcher("registry_test_typed", |v: &i32| *v == 1);
+
+        // Expect looking it up for another type to report the wrong type
+        assert!(matches!(
+            lookup_matcher::<String>("registry_test_typed"),
+            Lookup::WrongType
+        ));
+
+        // And expect looking up an unknown name to report it as missing
+        assert!(matches!(
+            lookup_matcher::<i32>("registry_test_unknown"),
+            Lookup::Missing
+        ));
+    }
+}
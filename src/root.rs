@@ -1,49 +1,217 @@
 use crate::expectation_list::ExpectationList;
-use crate::{CheckResult, Expectation, ExpectationBuilder};
-use std::fmt::Debug;
+use crate::projection::indent;
+use crate::{Expectation, ExpectationBuilder};
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::panic::Location;
+
+/// The value expectations are on, either owned or borrowed
+enum Value<'e, T> {
+    Owned(T),
+    Borrowed(&'e T),
+}
+
+impl<T> Value<'_, T> {
+    fn get(&self) -> &T {
+        match self {
+            Value::Owned(value) => value,
+            Value::Borrowed(value) => value,
+        }
+    }
+}
+
+/// Marks [RootExpectations] on an owned value, created by [expect](crate::expect)
+pub struct Owned;
+
+/// Marks [RootExpectations] on a borrowed value, created by [expect_ref](crate::expect_ref).
+///
+/// Methods that move the value out of the expectations, such as `unwrap_ok`, are only
+/// available for [Owned] values.
+pub struct Borrowed;
 
 /// Container for expectations on a value.
 ///
-/// Returned by [expect](crate::expect)
-pub struct RootExpectations<'e, T: Debug> {
-    value: T,
+/// Returned by [expect](crate::expect) and [expect_ref](crate::expect_ref),
+/// `V` is either [Owned] or [Borrowed]
+pub struct RootExpectations<'e, T: Debug, V = Owned> {
+    // Only None after the value has been moved out by extract or checked by into_result
+    value: Option<Value<'e, T>>,
     expectations: ExpectationList<'e, T>,
+    description: Option<&'e str>,
+    // Stop checking at the first failing expectation
+    fail_fast: bool,
+    // Where the expectations were created, reported on failure
+    location: &'static Location<'static>,
+    _v: PhantomData<V>,
 }
 
 impl<'e, T: Debug> RootExpectations<'e, T> {
+    #[track_caller]
     pub(crate) fn new(value: T) -> Self {
+        Self::with_value(Value::Owned(value))
+    }
+
+    /// Run all the expectations, move the value out and pass it to `extract`, disarming the
+    /// check on drop.
+    ///
+    /// Panics if any expectation fails. If `extract` fails, this panics with its message,
+    /// reported like the failure of an expectation.
+    #[track_caller]
+    pub(crate) fn extract<U>(mut self, extract: impl FnOnce(T) -> Result<U, String>) -> U {
+        let value = match self.value.take() {
+            Some(Value::Owned(value)) => value,
+            _ => unreachable!("owned expectations to hold an owned value"),
+        };
+        self.check_value(&value);
+        match extract(value) {
+            Ok(extracted) => extracted,
+            Err(message) => panic!("{}", self.report(&message)),
+        }
+    }
+}
+
+impl<'e, T: Debug> RootExpectations<'e, T, Borrowed> {
+    #[track_caller]
+    pub(crate) fn new_borrowed(value: &'e T) -> Self {
+        Self::with_value(Value::Borrowed(value))
+    }
+}
+
+impl<'e, T: Debug, V> RootExpectations<'e, T, V> {
+    #[track_caller]
+    fn with_value(value: Value<'e, T>) -> Self {
         RootExpectations {
             expectations: ExpectationList::new(),
-            value,
+            value: Some(value),
+            description: None,
+            fail_fast: false,
+            location: Location::caller(),
+            _v: PhantomData,
+        }
+    }
+
+    /// Describe the value, e.g. with the business rule it's subject to.
+    ///
+    /// The description applies to the whole chain, regardless of where it's added,
+    /// and is printed above the failure messages of any expectations that fail.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::EqualityExpectations;
+    ///
+    /// let age = 21;
+    /// expect(age).described_as("user age").to_not_equal(0);
+    /// ```
+    pub fn described_as(mut self, description: &'e str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Stop checking at the first failing expectation instead of running all of them.
+    ///
+    /// The expectations are checked in the order they were added, so later expectations can
+    /// rely on earlier ones holding. Applies to the whole chain, regardless of where it's added.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::{EqualityExpectations, IterableCountExpectations};
+    /// use rxpect::ExpectProjection;
+    ///
+    /// let values = vec![1, 2, 3];
+    /// expect(values)
+    ///     .fail_fast()
+    ///     .to_have_length(3)
+    ///     .projected_by(|it| it[2], |it| it.to_equal(3));
+    /// ```
+    pub fn fail_fast(mut self) -> Self {
+        self.fail_fast = true;
+        self
+    }
+
+    /// Check all expectations and panic with the failure message if any of them fail
+    #[track_caller]
+    fn check_value(&self, value: &T) {
+        if let Err(message) = self.result_for(value) {
+            panic!("{}", message);
+        }
+    }
+
+    /// Add the description and the location of the expectations to a failure message
+    fn report(&self, message: &str) -> String {
+        let message = match self.description {
+            Some(description) => format!("{}\n{}", description, indent(message)),
+            None => message.to_string(),
+        };
+        format!(
+            "{}\nat {}:{}",
+            message,
+            self.location.file(),
+            self.location.line()
+        )
+    }
+
+    /// Check all expectations and build the full failure message if any of them fail
+    fn result_for(&self, value: &T) -> Result<(), String> {
+        let result = match self.fail_fast {
+            true => self.expectations.check_fail_fast(value),
+            false => self.expectations.check(value),
+        };
+        match result.into_failure() {
+            None => Ok(()),
+            Some(failure) => Err(self.report(failure.message())),
         }
     }
 
+    /// The number of expectations that have been added to this value.
+    ///
+    /// Intended for testing helpers that build expectations
+    pub fn expectation_count(&self) -> usize {
+        self.expectations.expectation_count()
+    }
+
     /// Manually run all the expectations
     pub fn check(self) {
         drop(self)
     }
+
+    /// Run all the expectations and return the failure message instead of panicking.
+    ///
+    /// The expectations are not checked again when dropped.
+    /// ```
+    /// # use rxpect::expect;
+    /// # use rxpect::expectations::EqualityExpectations;
+    ///
+    /// let result = expect(1 + 1).to_equal(3).into_result();
+    /// assert!(result.is_err());
+    /// ```
+    pub fn into_result(mut self) -> Result<(), String> {
+        let value = self.value.take().expect("value to not have been moved out");
+        self.result_for(value.get())
+    }
 }
 
-impl<'e, T: Debug> ExpectationBuilder<'e, T> for RootExpectations<'e, T> {
+impl<'e, T: Debug, V> ExpectationBuilder<'e, T> for RootExpectations<'e, T, V> {
     /// Add an expectation to the list of expectations
-    fn to_pass(mut self, expectation: impl Expectation<T> + 'e) -> Self {
+    fn add_expectation(&mut self, expectation: impl Expectation<T> + 'e) -> &mut Self {
         self.expectations.push(expectation);
         self
     }
 }
 
-impl<'e, T: Debug> Drop for RootExpectations<'e, T> {
+impl<'e, T: Debug, V> Drop for RootExpectations<'e, T, V> {
     fn drop(&mut self) {
-        if let CheckResult::Fail(message) = self.expectations.check(&self.value) {
-            panic!("{}", message);
+        if let Some(value) = &self.value {
+            self.check_value(value.get());
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::expectations::{IterableEqualityExpectations, IterableProjectionExpectations};
     use crate::tests::TestExpectation;
-    use crate::{expect, CheckResult, ExpectationBuilder};
+    use crate::{expect, expect_ref, CheckResult, ExpectProjection, ExpectationBuilder};
 
     #[test]
     pub fn that_assert_runs_an_expectation() {
@@ -87,6 +255,147 @@ mod tests {
         assert!(*expected2.lock().unwrap());
     }
 
+    #[test]
+    pub fn that_add_expectation_adds_expectations_in_place() {
+        // Given an expectation
+        let (expectation, expected) = TestExpectation::new(CheckResult::Pass);
+
+        // And expectations it's added to by mutable reference
+        let mut expectations = expect(true);
+        expectations.add_expectation(expectation);
+
+        // When the expectations are checked
+        assert_eq!(1, expectations.expectation_count());
+        expectations.check();
+
+        // Then the expectation was run
+        assert!(*expected.lock().unwrap());
+    }
+
+    #[test]
+    pub fn that_also_adds_the_expectations_of_the_closure() {
+        // Given two expectations
+        let (expectation1, expected1) = TestExpectation::new(CheckResult::Pass);
+        let (expectation2, expected2) = TestExpectation::new(CheckResult::Pass);
+
+        // And expectations grouped with also
+        let expectations = expect(true)
+            .also(|it| it.to_pass(expectation1))
+            .also(|it| it.to_pass(expectation2));
+
+        // When the expectations are checked
+        assert_eq!(2, expectations.expectation_count());
+        expectations.check();
+
+        // Then both expectations were run
+        assert!(*expected1.lock().unwrap());
+        assert!(*expected2.lock().unwrap());
+    }
+
+    #[test]
+    pub fn that_expectation_count_counts_added_expectations() {
+        // Given two expectations
+        let (expectation1, _) = TestExpectation::new(CheckResult::Pass);
+        let (expectation2, _) = TestExpectation::new(CheckResult::Pass);
+
+        // When they are added to expectations
+        let expectations = expect(true).to_pass(expectation1).to_pass(expectation2);
+
+        // Then they are counted
+        assert_eq!(2, expectations.expectation_count());
+    }
+
+    #[test]
+    pub fn that_projected_expectations_count_as_one() {
+        // Given expectations with a projection containing two expectations
+        let (expectation1, _) = TestExpectation::new(CheckResult::Pass);
+        let (expectation2, _) = TestExpectation::new(CheckResult::Pass);
+        let expectations = expect(true).projected_by(
+            |_| 1,
+            |it| {
+                // Expect the inner list to count its own expectations
+                let it = it.to_pass(expectation1).to_pass(expectation2);
+                assert_eq!(2, it.expectation_count());
+                it
+            },
+        );
+
+        // Expect the projection to count as one expectation
+        assert_eq!(1, expectations.expectation_count());
+    }
+
+    #[test]
+    pub fn that_extract_runs_expectations_and_returns_the_value() {
+        // Given an expectation
+        let (expectation, expected) = TestExpectation::new(CheckResult::Pass);
+
+        // When the value is moved out of expectations containing it
+        let value = expect(7).to_pass(expectation).extract(Ok);
+
+        // Then the expectation was run and the value returned
+        assert!(*expected.lock().unwrap());
+        assert_eq!(7, value);
+    }
+
+    #[test]
+    #[should_panic(expected = "message")]
+    pub fn that_extract_panics_on_failure() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("message".to_owned()));
+
+        // Expect moving the value out to panic
+        expect(true).to_pass(expectation).extract(Ok);
+    }
+
+    #[test]
+    pub fn that_into_result_returns_ok_on_success() {
+        // Given an expectation that passes
+        let (expectation, expected) = TestExpectation::new(CheckResult::Pass);
+
+        // When the expectations are turned into a result
+        let result = expect(true).to_pass(expectation).into_result();
+
+        // Then the expectation was run and the result is Ok
+        assert!(*expected.lock().unwrap());
+        assert_eq!(Ok(()), result);
+    }
+
+    #[test]
+    pub fn that_into_result_returns_the_message_on_failure_without_panicking() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("message".to_owned()));
+
+        // When the expectations are turned into a result
+        let line = line!() + 1;
+        let result = expect(true).to_pass(expectation).into_result();
+
+        // Then the failure message is returned
+        assert_eq!(Err(format!("message\nat {}:{}", file!(), line)), result);
+    }
+
+    #[test]
+    pub fn that_fail_fast_stops_at_the_first_failure() {
+        // Given a passing, a failing and another failing expectation
+        let (passing, passed) = TestExpectation::new(CheckResult::Pass);
+        let (failing, failed) = TestExpectation::new(CheckResult::Fail("first".to_owned()));
+        let (skipped, checked) = TestExpectation::new(CheckResult::Fail("second".to_owned()));
+
+        // When the expectations are checked in fail fast mode
+        let line = line!() + 1;
+        let result = expect(true)
+            .to_pass(passing)
+            .to_pass(failing)
+            .fail_fast()
+            .to_pass(skipped)
+            .into_result();
+
+        // Then only the first failure is reported
+        assert_eq!(Err(format!("first\nat {}:{}", file!(), line)), result);
+        assert!(*passed.lock().unwrap());
+        assert!(*failed.lock().unwrap());
+        assert!(!*checked.lock().unwrap());
+    }
+
     #[test]
     #[should_panic]
     pub fn that_failure_panics() {
@@ -99,4 +408,56 @@ mod tests {
         // Expect a panic when checked
         expectations.check();
     }
+
+    #[test]
+    #[should_panic(expected = "user age\n  message")]
+    pub fn that_described_as_prefixes_failures() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("message".to_owned()));
+
+        // Expect the failure to be prefixed with the description
+        expect(17).to_pass(expectation).described_as("user age");
+    }
+
+    #[test]
+    #[should_panic(expected = "user age\n  message")]
+    pub fn that_described_as_prefixes_failures_when_moving_the_value_out() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("message".to_owned()));
+
+        // Expect the failure to be prefixed with the description
+        expect(17)
+            .described_as("user age")
+            .to_pass(expectation)
+            .extract(Ok);
+    }
+
+    #[test]
+    pub fn that_failures_report_the_location_of_expect() {
+        // Given an expectation that fails
+        let (expectation, _) = TestExpectation::new(CheckResult::Fail("message".to_owned()));
+
+        // When the expectations are created and checked
+        let line = line!() + 1;
+        let result = std::panic::catch_unwind(|| expect(true).to_pass(expectation).check());
+
+        // Then the failure points at the line calling expect
+        let panic = result.expect_err("expectations to fail");
+        let message = panic.downcast_ref::<String>().expect("a formatted message");
+        assert_eq!(&format!("message\nat {}:{}", file!(), line), message);
+    }
+
+    #[test]
+    pub fn that_expect_ref_does_not_move_the_value() {
+        // Given a collection
+        let values = vec![1, 2, 3];
+
+        // When expecting on a reference to it with iterable expectations
+        expect_ref(&values)
+            .to_have_item_at(0, 1)
+            .each_item(|it| it.to_pass(TestExpectation::new(CheckResult::Pass).0));
+
+        // Then it can be used again
+        assert_eq!(3, values.len());
+    }
 }
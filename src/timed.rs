@@ -0,0 +1,171 @@
+use crate::{expect, CheckResult, Expectation, ExpectationBuilder, Failure, RootExpectations};
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+/// Container for expectations on the value returned by a timed closure.
+///
+/// Returned by [expect_timed]
+pub struct TimedExpectations<'e, T: Debug> {
+    expectations: RootExpectations<'e, T>,
+    elapsed: Duration,
+}
+
+/// Create expectations for the value returned by `operation`, measuring how long it took.
+///
+/// Only the call to `operation` is timed. Besides the usual expectations on the returned value,
+/// [to_complete_within](TimedExpectations::to_complete_within) puts an upper bound on the time.
+/// This is meant as a coarse guard in integration tests, not as a benchmark.
+/// ```
+/// use rxpect::expect_timed;
+/// use rxpect::expectations::EqualityExpectations;
+/// use std::time::Duration;
+///
+/// expect_timed(|| (1..=100).sum::<u32>())
+///     .to_complete_within(Duration::from_secs(1))
+///     .to_equal(5050);
+/// ```
+#[track_caller]
+pub fn expect_timed<'e, T: Debug>(operation: impl FnOnce() -> T) -> TimedExpectations<'e, T> {
+    let start = Instant::now();
+    let value = operation();
+    let elapsed = start.elapsed();
+    TimedExpectations {
+        expectations: expect(value),
+        elapsed,
+    }
+}
+
+impl<'e, T: Debug> TimedExpectations<'e, T> {
+    /// How long the closure took to return
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Expect the closure to have returned within `limit`
+    pub fn to_complete_within(mut self, limit: Duration) -> Self {
+        self.expectations
+            .add_expectation(CompletionTimeExpectation {
+                elapsed: self.elapsed,
+                limit,
+            });
+        self
+    }
+
+    /// Describe the value, see [RootExpectations::described_as]
+    pub fn described_as(mut self, description: &'e str) -> Self {
+        self.expectations = self.expectations.described_as(description);
+        self
+    }
+
+    /// Stop checking at the first failing expectation, see [RootExpectations::fail_fast]
+    pub fn fail_fast(mut self) -> Self {
+        self.expectations = self.expectations.fail_fast();
+        self
+    }
+
+    /// The number of expectations that have been added, including
+    /// [to_complete_within](TimedExpectations::to_complete_within)
+    pub fn expectation_count(&self) -> usize {
+        self.expectations.expectation_count()
+    }
+
+    /// Manually run all the expectations
+    pub fn check(self) {
+        drop(self)
+    }
+
+    /// Run all the expectations and return the failure message instead of panicking,
+    /// see [RootExpectations::into_result]
+    pub fn into_result(self) -> Result<(), String> {
+        self.expectations.into_result()
+    }
+}
+
+impl<'e, T: Debug> ExpectationBuilder<'e, T> for TimedExpectations<'e, T> {
+    /// Add an expectation to the list of expectations
+    fn add_expectation(&mut self, expectation: impl Expectation<T> + 'e) -> &mut Self {
+        self.expectations.add_expectation(expectation);
+        self
+    }
+}
+
+/// Expectation for to_complete_within
+struct CompletionTimeExpectation {
+    elapsed: Duration,
+    limit: Duration,
+}
+
+impl<T: Debug> Expectation<T> for CompletionTimeExpectation {
+    fn check(&self, _: &T) -> CheckResult {
+        if self.elapsed <= self.limit {
+            CheckResult::Pass
+        } else {
+            CheckResult::FailWith(
+                Failure::new(format!(
+                    "Expected the operation to complete within {:?}, but it took {:?}",
+                    self.limit, self.elapsed
+                ))
+                .with_expected(format!("{:?}", self.limit))
+                .with_actual(format!("{:?}", self.elapsed))
+                .with_matcher("to_complete_within"),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expect_timed;
+    use crate::expectations::EqualityExpectations;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    pub fn that_expect_timed_runs_expectations_on_the_returned_value() {
+        // Expect the returned value to be checked along with the time
+        expect_timed(|| "foo")
+            .to_equal("foo")
+            .to_complete_within(Duration::from_secs(5));
+    }
+
+    #[test]
+    pub fn that_elapsed_covers_the_closure() {
+        // Given a closure that sleeps
+        let timed = expect_timed(|| sleep(Duration::from_millis(10)));
+
+        // Expect the sleep to be included in the elapsed time
+        assert!(timed.elapsed() >= Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected the operation to complete within 1ms, but it took")]
+    pub fn that_to_complete_within_does_not_accept_slow_operations() {
+        // Expect a closure that takes longer than the limit to fail
+        expect_timed(|| sleep(Duration::from_millis(20)))
+            .to_complete_within(Duration::from_millis(1));
+    }
+
+    #[test]
+    pub fn that_root_expectation_methods_are_available() {
+        // When a slow operation is described, counted and checked without panicking
+        let timed = expect_timed(|| sleep(Duration::from_millis(20)))
+            .described_as("slow operation")
+            .fail_fast()
+            .to_complete_within(Duration::from_millis(1));
+        assert_eq!(1, timed.expectation_count());
+        let result = timed.into_result();
+
+        // Then the failure is described
+        let message = result.expect_err("the operation to be too slow");
+        assert!(message.starts_with("slow operation\n  Expected the operation to complete within"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expectation failed (expected == actual)")]
+    pub fn that_expect_timed_reports_failures_on_the_value() {
+        // Expect a differing value to fail even when the closure is fast enough
+        expect_timed(|| 1)
+            .to_complete_within(Duration::from_secs(5))
+            .to_equal(2);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{expect, RootExpectations};
+use alloc::string::String;
+
+/// Create expectations for the text written by a closure.
+///
+/// The closure is given an empty [String] to write into,
+/// either directly or through [std::fmt::Write].
+/// ```
+/// use rxpect::expect_written;
+/// use rxpect::expectations::StringExpectations;
+/// use std::fmt::Write;
+///
+/// fn render(w: &mut impl Write) -> std::fmt::Result {
+///     writeln!(w, "header")?;
+///     writeln!(w, "body")
+/// }
+///
+/// expect_written(|w| render(w).unwrap()).to_contain("header");
+/// ```
+#[track_caller]
+pub fn expect_written<'e>(write: impl FnOnce(&mut String)) -> RootExpectations<'e, String> {
+    let mut written = String::new();
+    write(&mut written);
+    expect(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expect_written;
+    use crate::expectations::{EqualityExpectations, StringExpectations};
+    use std::fmt::Write;
+
+    #[test]
+    pub fn that_expect_written_expects_on_the_written_content() {
+        // Expect the content written by the closure to be checked
+        expect_written(|w| write!(w, "foo {}", 1).unwrap()).to_equal("foo 1".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected \"foo\\nbar\" to contain \"baz\"")]
+    pub fn that_expect_written_reports_the_written_content() {
+        // Expect a failure to include all the written content
+        expect_written(|w| w.push_str("foo\nbar")).to_contain("baz");
+    }
+}
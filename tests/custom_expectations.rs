@@ -0,0 +1,110 @@
+//! Expectations defined outside of the crate, the way a third-party crate would add them
+use rxpect::expectations::EqualityExpectations;
+use rxpect::{expect, CheckResult, ExpectProjection, Expectation, ExpectationBuilder};
+use std::fmt::Debug;
+
+#[derive(Debug)]
+struct Temperature(f64);
+
+/// Expectation for to_be_freezing
+struct FreezingExpectation;
+
+impl Expectation<Temperature> for FreezingExpectation {
+    fn check(&self, value: &Temperature) -> CheckResult {
+        if value.0 <= 0.0 {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail(format!("Expected {:?} to be freezing", value))
+        }
+    }
+}
+
+/// Extension trait for expectations on temperatures
+trait TemperatureExpectations {
+    fn to_be_freezing(self) -> Self;
+}
+
+impl<'e, B> TemperatureExpectations for B
+where
+    B: ExpectationBuilder<'e, Temperature>,
+{
+    fn to_be_freezing(self) -> Self {
+        self.to_pass(FreezingExpectation)
+    }
+}
+
+#[test]
+pub fn that_custom_extension_traits_can_be_used() {
+    // Expect a custom expectation to chain with the built-in ones
+    expect(Temperature(-4.0))
+        .to_be_freezing()
+        .to_pass(FreezingExpectation);
+}
+
+#[test]
+#[should_panic(expected = "Expected Temperature(21.0) to be freezing")]
+pub fn that_custom_extension_traits_report_failures() {
+    // Expect a warm temperature to not be freezing
+    expect(Temperature(21.0)).to_be_freezing();
+}
+
+#[test]
+pub fn that_custom_expectations_can_be_used_directly() {
+    // Expect custom to accept an expectation alongside the built-in ones
+    expect(Temperature(0.0))
+        .custom(FreezingExpectation)
+        .projected_by(|it| it.0 as i32, |it| it.to_equal(0));
+}
+
+/// Generic expectation that works on any value
+struct DebugLengthExpectation(usize);
+
+impl<T: Debug> Expectation<T> for DebugLengthExpectation {
+    fn check(&self, value: &T) -> CheckResult {
+        let length = format!("{:?}", value).len();
+        if length <= self.0 {
+            CheckResult::Pass
+        } else {
+            CheckResult::Fail(format!(
+                "Expected {:?} to be at most {} characters when debug printed, but it was {}",
+                value, self.0, length
+            ))
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "to be at most 3 characters when debug printed, but it was 9")]
+pub fn that_generic_custom_expectations_can_be_used() {
+    // Expect a generic custom expectation to fail for a long value
+    expect(vec![1, 2, 3]).custom(DebugLengthExpectation(3));
+}
+
+/// Builder outside of the crate, collecting expectations without checking them
+struct CollectingBuilder(Vec<Box<dyn Expectation<Temperature>>>);
+
+impl ExpectationBuilder<'static, Temperature> for CollectingBuilder {
+    fn add_expectation(
+        &mut self,
+        expectation: impl Expectation<Temperature> + 'static,
+    ) -> &mut Self {
+        self.0.push(Box::new(expectation));
+        self
+    }
+}
+
+#[test]
+pub fn that_custom_builders_work_with_extension_traits() {
+    // Given a builder implementing add_expectation
+    let builder = CollectingBuilder(vec![]);
+
+    // When expectations are added through an extension trait
+    let builder = builder.to_be_freezing().custom(FreezingExpectation);
+
+    // Then they are added to the builder
+    assert_eq!(2, builder.0.len());
+    assert!(matches!(
+        builder.0[0].check(&Temperature(-1.0)),
+        CheckResult::Pass
+    ));
+}
@@ -0,0 +1,22 @@
+//! Expectations used with a glob import of all extension traits, the way most tests import them
+use rxpect::expect;
+use rxpect::expectations::*;
+use std::time::Duration;
+
+#[test]
+pub fn that_glob_imported_expectations_do_not_collide() {
+    // Expect methods available on several kinds of values to resolve to a single trait
+    expect(vec![1, 2, 3])
+        .to_have_length(3)
+        .to_have_vec_length(3)
+        .to_have_capacity_at_least(3)
+        .to_have_item_at(1, 2)
+        .to_equal(vec![1, 2, 3]);
+    expect("foo").to_contain("o").to_not_equal("bar");
+    expect(Some(1)).to_be_none_or_matching(|it| *it == 1);
+    expect(7)
+        .to_be_positive()
+        .to_be_between(1, 10)
+        .to_be_within_percent(7, 1.0);
+    expect(Duration::from_secs(7)).to_be_within_percent(Duration::from_secs(7), 1.0);
+}